@@ -0,0 +1,160 @@
+//! ANSI SGR escape sequences, `ansi-fg` and `ansi-bg` for 24-bit color,
+//! `-256` and `-16` suffixes pick the perceptually nearest indexed color instead
+//!
+//! The 16 base colors are matched against the xterm defaults, terminals that
+//! use another theme will show whatever their theme assigns to that index
+
+use crate::gamut::delta_eok;
+use palette::{IntoColor, Oklab, Srgb};
+
+/// Channel levels of the 6x6x6 color cube at index 16..=231
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+#[rustfmt::skip]
+const BASE: [[u8; 3]; 16] = [
+    [0, 0, 0], [205, 0, 0], [0, 205, 0], [205, 205, 0],
+    [0, 0, 238], [205, 0, 205], [0, 205, 205], [229, 229, 229],
+    [127, 127, 127], [255, 0, 0], [0, 255, 0], [255, 255, 0],
+    [92, 92, 255], [255, 0, 255], [0, 255, 255], [255, 255, 255],
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnsiLayer {
+    Foreground,
+    Background,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnsiDepth {
+    TrueColor,
+    Xterm256,
+    Base16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ansi {
+    pub layer: AnsiLayer,
+    pub depth: AnsiDepth,
+}
+
+impl Ansi {
+    /// Parses a format like `ansi-fg` or `ansi-bg-256`
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.strip_prefix("ansi-")?;
+        let (layer, depth) = name.split_once('-').unwrap_or((name, ""));
+
+        let layer = match layer {
+            "fg" => AnsiLayer::Foreground,
+            "bg" => AnsiLayer::Background,
+            _ => return None,
+        };
+
+        let depth = match depth {
+            "" => AnsiDepth::TrueColor,
+            "256" => AnsiDepth::Xterm256,
+            "16" => AnsiDepth::Base16,
+            _ => return None,
+        };
+
+        Some(Self { layer, depth })
+    }
+
+    pub fn format(&self, color: Srgb<u8>) -> String {
+        let (r, g, b) = color.into_components();
+        let layer = match self.layer {
+            AnsiLayer::Foreground => 38,
+            AnsiLayer::Background => 48,
+        };
+
+        match self.depth {
+            AnsiDepth::TrueColor => format!("\x1b[{layer};2;{r};{g};{b}m"),
+            AnsiDepth::Xterm256 => format!("\x1b[{layer};5;{}m", nearest_256(color)),
+            AnsiDepth::Base16 => {
+                let index = nearest(color, BASE.iter().copied().map(Srgb::from).zip(0..));
+                let code = match index {
+                    0..8 => 30 + index,
+                    _ => 90 + index - 8,
+                };
+
+                // background codes are offset by 10 from the foreground ones
+                match self.layer {
+                    AnsiLayer::Foreground => format!("\x1b[{code}m"),
+                    AnsiLayer::Background => format!("\x1b[{}m", code + 10),
+                }
+            }
+        }
+    }
+}
+
+/// Nearest color of the cube and grayscale ramp, the first 16 are left out
+/// since they depend on the terminal theme
+fn nearest_256(color: Srgb<u8>) -> u8 {
+    let cube = (0..216u8).map(|i| {
+        let [r, g, b] = [i / 36, i / 6 % 6, i % 6].map(|c| CUBE[usize::from(c)]);
+        let rgb = Srgb::new(r, g, b);
+
+        (rgb, 16 + i)
+    });
+
+    let gray = (0..24u8).map(|i| {
+        let level = 8 + i * 10;
+
+        (Srgb::new(level, level, level), 232 + i)
+    });
+
+    nearest(color, cube.chain(gray))
+}
+
+fn nearest(color: Srgb<u8>, candidates: impl Iterator<Item = (Srgb<u8>, u8)>) -> u8 {
+    let oklab = |color: Srgb<u8>| -> Oklab { color.into_format::<f32>().into_color() };
+    let target = oklab(color);
+
+    candidates
+        .map(|(candidate, index)| (delta_eok(target, oklab(candidate)), index))
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map_or(0, |(_, index)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(name: &str, [r, g, b]: [u8; 3]) -> String {
+        Ansi::from_name(name).unwrap().format(Srgb::new(r, g, b))
+    }
+
+    #[test]
+    fn from_name() {
+        let ansi = Ansi::from_name("ansi-bg-256").unwrap();
+        assert_eq!(ansi.layer, AnsiLayer::Background);
+        assert_eq!(ansi.depth, AnsiDepth::Xterm256);
+        assert_eq!(
+            Ansi::from_name("ansi-fg").unwrap().depth,
+            AnsiDepth::TrueColor
+        );
+        assert_eq!(Ansi::from_name("ansi-fg-8"), None);
+        assert_eq!(Ansi::from_name("ansi-ul"), None);
+        assert_eq!(Ansi::from_name("ansi"), None);
+    }
+
+    #[test]
+    fn truecolor() {
+        assert_eq!(format("ansi-fg", [255, 136, 0]), "\x1b[38;2;255;136;0m");
+        assert_eq!(format("ansi-bg", [1, 2, 3]), "\x1b[48;2;1;2;3m");
+    }
+
+    #[test]
+    fn xterm_256() {
+        assert_eq!(format("ansi-fg-256", [255, 0, 0]), "\x1b[38;5;196m");
+        assert_eq!(format("ansi-fg-256", [250, 250, 250]), "\x1b[38;5;231m");
+        assert_eq!(format("ansi-bg-256", [128, 128, 128]), "\x1b[48;5;244m");
+    }
+
+    #[test]
+    fn base_16() {
+        assert_eq!(format("ansi-fg-16", [0, 0, 0]), "\x1b[30m");
+        assert_eq!(format("ansi-fg-16", [200, 10, 10]), "\x1b[31m");
+        assert_eq!(format("ansi-fg-16", [255, 0, 0]), "\x1b[91m");
+        assert_eq!(format("ansi-bg-16", [255, 255, 255]), "\x1b[107m");
+    }
+}
@@ -0,0 +1,174 @@
+//! Naive device CMYK
+//!
+//! There is no color profile involved, channels are converted to and from sRGB with
+//!
+//! ```text
+//! k = 1 - max(r, g, b)
+//! c = (1 - r - k) / (1 - k)
+//! r = (1 - c) * (1 - k)
+//! ```
+//!
+//! and the same for magenta/green and yellow/blue, so the values are only an
+//! approximation of what a printer would actually use
+
+use palette::{Srgb, Srgba, WithAlpha};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cmyk {
+    pub cyan: f32,
+    pub magenta: f32,
+    pub yellow: f32,
+    pub black: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cmyka {
+    pub cyan: f32,
+    pub magenta: f32,
+    pub yellow: f32,
+    pub black: f32,
+    pub alpha: f32,
+}
+
+impl Cmyk {
+    pub fn new(cyan: f32, magenta: f32, yellow: f32, black: f32) -> Self {
+        Self {
+            cyan,
+            magenta,
+            yellow,
+            black,
+        }
+    }
+
+    pub fn with_alpha(self, alpha: f32) -> Cmyka {
+        let Self {
+            cyan,
+            magenta,
+            yellow,
+            black,
+        } = self;
+
+        Cmyka {
+            cyan,
+            magenta,
+            yellow,
+            black,
+            alpha,
+        }
+    }
+
+    pub fn into_components(self) -> (f32, f32, f32, f32) {
+        (self.cyan, self.magenta, self.yellow, self.black)
+    }
+}
+
+impl Cmyka {
+    pub fn without_alpha(self) -> Cmyk {
+        Cmyk::new(self.cyan, self.magenta, self.yellow, self.black)
+    }
+
+    pub fn into_components(self) -> (f32, f32, f32, f32, f32) {
+        (self.cyan, self.magenta, self.yellow, self.black, self.alpha)
+    }
+}
+
+impl From<[f32; 4]> for Cmyk {
+    fn from([c, m, y, k]: [f32; 4]) -> Self {
+        Self::new(c, m, y, k)
+    }
+}
+
+impl From<[f32; 5]> for Cmyka {
+    fn from([c, m, y, k, a]: [f32; 5]) -> Self {
+        Cmyk::new(c, m, y, k).with_alpha(a)
+    }
+}
+
+impl From<Cmyk> for Cmyka {
+    fn from(color: Cmyk) -> Self {
+        color.with_alpha(1.0)
+    }
+}
+
+impl From<Srgb> for Cmyk {
+    fn from(color: Srgb) -> Self {
+        let (r, g, b) = color.into_components();
+        let black = 1.0 - r.max(g).max(b);
+
+        if black >= 1.0 {
+            return Self::new(0.0, 0.0, 0.0, 1.0);
+        }
+
+        let channel = |c: f32| (1.0 - c - black) / (1.0 - black);
+
+        Self::new(channel(r), channel(g), channel(b), black)
+    }
+}
+
+impl From<Cmyk> for Srgb {
+    fn from(color: Cmyk) -> Self {
+        let channel = |c: f32| (1.0 - c) * (1.0 - color.black);
+
+        Srgb::new(
+            channel(color.cyan),
+            channel(color.magenta),
+            channel(color.yellow),
+        )
+    }
+}
+
+impl From<Srgba> for Cmyka {
+    fn from(color: Srgba) -> Self {
+        Cmyk::from(color.color).with_alpha(color.alpha)
+    }
+}
+
+impl From<Cmyka> for Srgba {
+    fn from(color: Cmyka) -> Self {
+        Srgb::from(color.without_alpha()).with_alpha(color.alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_srgb() {
+        assert_eq!(
+            Cmyk::from(Srgb::new(1.0, 0.5, 0.0)),
+            Cmyk::new(0.0, 0.5, 1.0, 0.0)
+        );
+        assert_eq!(
+            Cmyk::from(Srgb::new(0.5, 0.5, 0.5)),
+            Cmyk::new(0.0, 0.0, 0.0, 0.5)
+        );
+        assert_eq!(
+            Cmyk::from(Srgb::new(0.0, 0.0, 0.0)),
+            Cmyk::new(0.0, 0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn into_srgb() {
+        assert_eq!(
+            Srgb::from(Cmyk::new(0.0, 0.5, 1.0, 0.0)),
+            Srgb::new(1.0, 0.5, 0.0)
+        );
+        assert_eq!(
+            Srgb::from(Cmyk::new(1.0, 0.0, 0.0, 0.5)),
+            Srgb::new(0.0, 0.5, 0.5)
+        );
+        assert_eq!(
+            Srgb::from(Cmyk::new(0.3, 0.6, 0.9, 1.0)),
+            Srgb::new(0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn alpha_is_kept() {
+        let cmyka = Cmyka::from(Srgba::new(0.0, 1.0, 1.0, 0.25));
+        assert_eq!(cmyka, Cmyk::new(1.0, 0.0, 0.0, 0.0).with_alpha(0.25));
+        assert_eq!(Srgba::from(cmyka), Srgba::new(0.0, 1.0, 1.0, 0.25));
+    }
+}
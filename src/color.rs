@@ -1,23 +1,194 @@
+use crate::ansi::Ansi;
+use crate::cmyk::{Cmyk, Cmyka};
 use crate::config::Palette;
+use crate::css::Css;
+use crate::encoding::{
+    A98Rgb, A98Rgba, DisplayP3, DisplayP3a, ProPhotoRgb, ProPhotoRgba, Rec2020, Rec2020a,
+};
+use crate::format::{Case, FormatOptions, FormatParams, FormatSpec};
+use crate::gamut::{self, Gamut, GamutMapping, TargetGamut};
+use crate::hex::{HexAlpha, HexFormat};
+use crate::hpluv::{Hpluv, Hpluva};
+use crate::kelvin;
+use crate::names::{self, DEFAULT_NAMES, NameDatabase};
+use crate::packed::{self, Packing};
+use crate::parser::{Component, Function, ParseError, offset_of};
+use crate::shader::Shader;
+use crate::template::Template;
 use crate::{Error, Result};
-use itertools::Itertools;
+use palette::chromatic_adaptation::AdaptFrom;
+use palette::convert::FromColorUnclamped;
 use palette::rgb::{Rgb, Rgba};
-use palette::{Hsl, Hsla, Hsv, Hsva, IntoColor, Srgb, Srgba, WithAlpha};
+use palette::white_point::{D50, D65, WhitePoint};
+use palette::{
+    Alpha, Hsl, Hsla, Hsluv, Hsluva, Hsv, Hsva, Hwb, Hwba, IntoColor, Lab, Laba, Lch, Lcha, Lchuva,
+    LinSrgb, LinSrgba, Oklab, Oklaba, Oklch, Oklcha, Srgb, Srgba, WithAlpha, Xyz, Xyza, Yxy, Yxya,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de, ser};
+use std::borrow::Cow;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
+/// `0..=255` channel stored as a float, so `rgb(50% 0 0)` isn't rounded
+const CHANNEL: Component = Component::Number {
+    scale: 1.0 / 255.0,
+    percent: 1.0,
+    min: 0.0,
+    max: 1.0,
+};
+const UNIT: Component = Component::Number {
+    scale: 1.0,
+    percent: 1.0,
+    min: 0.0,
+    max: 1.0,
+};
+/// Coordinates of `color()` and XYZ, which may lie outside of the gamut
+const COORD: Component = Component::Number {
+    scale: 1.0,
+    percent: 1.0,
+    min: f32::NEG_INFINITY,
+    max: f32::INFINITY,
+};
+/// Saturation, lightness, whiteness and blackness in the modern syntax,
+/// where bare numbers are percentages like `hsl(120 100 50)` in CSS
+const HSL_PERCENT: Component = Component::Number {
+    scale: 0.01,
+    percent: 1.0,
+    min: 0.0,
+    max: 1.0,
+};
+/// y of xyY, converting `0` to XYZ would divide by zero
+const XYY_Y: Component = Component::Number {
+    scale: 1.0,
+    percent: 1.0,
+    min: f32::MIN_POSITIVE,
+    max: 1.0,
+};
+const HUE: Component = Component::Hue;
+const LAB_L: Component = Component::Number {
+    scale: 1.0,
+    percent: 100.0,
+    min: 0.0,
+    max: 100.0,
+};
+const LAB_AB: Component = Component::Number {
+    scale: 1.0,
+    percent: 125.0,
+    min: f32::NEG_INFINITY,
+    max: f32::INFINITY,
+};
+const LCH_C: Component = Component::Number {
+    scale: 1.0,
+    percent: 150.0,
+    min: 0.0,
+    max: f32::INFINITY,
+};
+const OKLAB_AB: Component = Component::Number {
+    scale: 1.0,
+    percent: 0.4,
+    min: f32::NEG_INFINITY,
+    max: f32::INFINITY,
+};
+const OKLCH_C: Component = Component::Number {
+    scale: 1.0,
+    percent: 0.4,
+    min: 0.0,
+    max: f32::INFINITY,
+};
+const HSLUV_SL: Component = Component::Number {
+    scale: 1.0,
+    percent: 100.0,
+    min: 0.0,
+    max: 100.0,
+};
+
+/// Steps per unit of the Oklab components that [`Color`] is compared with
+const CANONICAL_STEPS: f32 = 10_000.0;
+
+pub type LabD50 = Lab<D50>;
+pub type LabaD50 = Laba<D50>;
+pub type LchD50 = Lch<D50>;
+pub type LchaD50 = Lcha<D50>;
+pub type LabD65 = Lab<D65>;
+pub type LabaD65 = Laba<D65>;
+pub type LchD65 = Lch<D65>;
+pub type LchaD65 = Lcha<D65>;
+pub type XyzD50 = Xyz<D50>;
+pub type XyzaD50 = Xyza<D50>;
+
 macro_rules! impl_color {
-    ($format:ty) => {
-        impl Into<$format> for Color {
-            fn into(self) -> $format {
-                match self {
-                    Self::Srgb(color) => color.into_color(),
-                    Self::Rgba(color) => color.into_color(),
-                    Self::Hsla(color) => color.into_color(),
-                    Self::Hsva(color) => color.into_color(),
+    // `$own` is the variant the format is authored in, it's converted without clamping
+    // so emitting a color in its own format is lossless as long as it's within the gamut
+    ($format:ty $(: $own:ident)? $(, gamut = $gamut:ty)?) => {
+        impl_color!(@gamut $format $(, $gamut)?);
+
+        impl From<Color> for $format {
+            fn from(color: Color) -> $format {
+                $(if let Color::$own(color) = color {
+                    return <$format>::from_color_unclamped(color);
+                })?
+
+                match color {
+                    Color::Srgb(color) => color.into_color(),
+                    Color::Rgba(color) => color.into_color(),
+                    Color::LinSrgba(color) => Srgba::from_linear(color).into_color(),
+                    Color::Hsla(color) => color.into_color(),
+                    Color::Hsva(color) => color.into_color(),
+                    Color::Hwba(color) => color.into_color(),
+                    Color::Cmyka(color) => Srgba::from(color).into_color(),
+                    Color::Hsluva(color) => color.into_color(),
+                    Color::Hpluva(color) => Lchuva::from(color).into_color(),
+                    Color::Oklaba(color) => color.into_color(),
+                    Color::Oklcha(color) => color.into_color(),
+                    Color::LabaD65(color) => color.into_color(),
+                    Color::LchaD65(color) => color.into_color(),
+                    Color::Xyza(color) => color.into_color(),
+                    Color::Yxya(color) => color.into_color(),
+                    Color::DisplayP3a(color) => Xyza::from_color_unclamped(color).into_color(),
+                    Color::Rec2020a(color) => Xyza::from_color_unclamped(color).into_color(),
+                    Color::A98Rgba(color) => Xyza::from_color_unclamped(color).into_color(),
+                    Color::LabaD50(color) => adapt::<_, Xyz<D65>, D50, D65>(color).into_color(),
+                    Color::LchaD50(color) => adapt::<_, Xyz<D65>, D50, D65>(color).into_color(),
+                    Color::XyzaD50(color) => adapt::<_, Xyz<D65>, D50, D65>(color).into_color(),
+                    Color::ProPhotoRgba(color) => adapt::<_, Xyz<D65>, D50, D65>(color).into_color(),
+                }
+            }
+        }
+    };
+    // formats that palette can't reach from every variant are only converted
+    // directly from the listed variants, everything else goes through the hub
+    ($format:ty $(: $own:ident)? => $hub:ty: $($variant:ident),+ $(; gamut = $gamut:ty)?) => {
+        impl_color!(@gamut $format $(, $gamut)?);
+
+        impl From<Color> for $format {
+            fn from(color: Color) -> $format {
+                $(if let Color::$own(color) = color {
+                    return <$format>::from_color_unclamped(color);
+                })?
+
+                match color {
+                    $(Color::$variant(color) => color.into_color(),)+
+                    color => <$hub>::from(color).into_color(),
                 }
             }
         }
     };
+    // colors within the gamut are left untouched by `map_gamut`, including the own variant
+    (@gamut $format:ty, $gamut:ty) => {
+        impl TargetGamut for $format {
+            fn map_gamut(color: Color, mapping: GamutMapping) -> Color {
+                color.map_gamut::<$gamut>(mapping)
+            }
+        }
+    };
+    (@gamut $format:ty) => {
+        impl TargetGamut for $format {
+            fn map_gamut(color: Color, _: GamutMapping) -> Color {
+                color
+            }
+        }
+    };
 }
 
 macro_rules! impl_from_color {
@@ -31,14 +202,32 @@ macro_rules! impl_from_color {
     };
 }
 
+// returns the color from the function if its name matches, evaluates to all names otherwise
 macro_rules! impl_match_color {
-    ($src:ident, $($name:literal: $from:ident<$t:tt, $n:literal>),+ $(,)?) => {
-        $(if $src.starts_with(concat!($name, "(")) {
-            let params = parse_params::<$t, $n>($src)?;
-            let color = Color::$from(params);
-            return Ok(color);
+    ($f:ident, $key:expr, $($($name:literal)|+: $from:ident<$t:ty>[$($c:expr),+] $(/ $alpha:ident[$ac:expr])?),+ $(,)?) => {{
+        $(if $($key == $name)||+ {
+            $f.expect_len(&impl_match_color!(@lens [$($c),+] $(, $alpha)?))?;
+            impl_match_color!(@alpha $f, $t, [$($c),+] $(, $alpha[$ac])?);
+
+            let params = $f.params::<$t, _>([$($c),+])?;
+            return Ok(Color::$from(params));
         })+
+
+        [$($($name),+),+]
+    }};
+    (@lens [$($c:expr),+], $alpha:ident) => {
+        [[$($c),+].len(), [$($c),+].len() + 1]
+    };
+    (@lens [$($c:expr),+]) => {
+        [[$($c),+].len()]
+    };
+    (@alpha $f:ident, $t:ty, [$($c:expr),+], $alpha:ident[$ac:expr]) => {
+        if $f.len() == [$($c),+].len() + 1 {
+            let params = $f.params_with_alpha::<$t, _>([$($c),+, $ac])?;
+            return Ok(Color::$alpha(params));
+        }
     };
+    (@alpha $f:ident, $t:ty, [$($c:expr),+]) => {};
 }
 
 macro_rules! impl_from_color_map {
@@ -50,29 +239,99 @@ macro_rules! impl_from_color_map {
 }
 
 macro_rules! impl_to_color_map {
-    ($($name:literal: $fname:ident -> $to:tt<$($t:tt),+>(|$($n:ident),+| $($fmt:tt)*)),+ $(,)?) => {
+    ($($($name:literal)|+: $fname:ident -> $to:tt$(<$($t:tt),+>)?($($body:tt)*)),+ $(,)?) => {
         $(pub fn $fname(self) -> String {
-            let __into_color: $to = self.into();
-            let ($($n),+) = __into_color.into_format::<$($t),+>().into_components();
+            let __into_color = <$to as TargetGamut>::map_gamut(self, GamutMapping::default());
 
-            format!($($fmt)*)
+            impl_to_color_map!(@format __into_color, FormatParams::default(), $to$(<$($t),+>)?, ($($body)*))
         })+
 
         pub fn to_format(self, format: &str) -> Result<String> {
-            match format {
-                $($name => Ok(self.$fname())),+,
-                _ => Err(Error::FailedToParseFormat(format.to_owned()))
+            self.to_format_with(format, &FormatOptions::default())
+        }
+
+        /// Like [`Color::to_format`] but with control over how out of gamut colors are handled
+        /// and with the user defined formats of the config
+        ///
+        /// `format` can have parameters like `hsl(precision=1)`, see [`FormatParams`]
+        pub fn to_format_with(self, format: &str, options: &FormatOptions) -> Result<String> {
+            let mapping = options.mapping;
+            let FormatSpec { name, mut params } = FormatSpec::parse(format)?;
+
+            let color = match name {
+                $($($name)|+ => {
+                    let __into_color = <$to as TargetGamut>::map_gamut(self, mapping);
+
+                    impl_to_color_map!(@format __into_color, params, $to$(<$($t),+>)?, ($($body)*))
+                }),+
+                _ => self
+                    .format_dynamic(name, &mut params, options)
+                    .ok_or_else(|| Error::FailedToParseFormat(format.to_owned()))??,
+            };
+
+            if !params.flags.is_empty() {
+                return Err(Error::FailedToParseFormat(format.to_owned()));
             }
+
+            Ok(params.finish(color))
         }
     };
+    // a list of components, separated and rounded according to the params
+    (@format $color:ident, $params:expr, $to:tt$(<$($t:tt),+>)?, (|$($n:ident),+| [$($c:expr),+])) => {{
+        let __into_color: $to = $color.into();
+        $(let __into_color = __into_color.into_format::<$($t),+>();)?
+        let ($($n),+) = __into_color.into_components();
+
+        $params.join(&[$(f32::from($c)),+])
+    }};
+    (@format $color:ident, $params:expr, $to:tt$(<$($t:tt),+>)?, (|$($n:ident),+| $($fmt:tt)*)) => {{
+        let __into_color: $to = $color.into();
+        $(let __into_color = __into_color.into_format::<$($t),+>();)?
+        let ($($n),+) = __into_color.into_components();
+
+        format!($($fmt)*)
+    }};
 }
 
+macro_rules! impl_color_from {
+    ($($from:ty => $variant:ident),+ $(,)?) => {
+        $(impl From<$from> for Color {
+            fn from(color: $from) -> Color {
+                Color::$variant(color.into())
+            }
+        })+
+    };
+}
+
+/// A color in the space it was authored in
+///
+/// Components are kept exactly as parsed and only converted once when the color
+/// is emitted, so emitting a color in its own format gives back the same values
+/// unless they're outside of the gamut of the format
 #[derive(Debug, Clone)]
 pub enum Color {
     Srgb(Srgb),
     Rgba(Rgba),
     Hsla(Hsla),
     Hsva(Hsva),
+    Hwba(Hwba),
+    Cmyka(Cmyka),
+    Hsluva(Hsluva),
+    Hpluva(Hpluva),
+    Oklaba(Oklaba),
+    Oklcha(Oklcha),
+    LabaD50(LabaD50),
+    LchaD50(LchaD50),
+    LabaD65(LabaD65),
+    LchaD65(LchaD65),
+    LinSrgba(LinSrgba),
+    DisplayP3a(DisplayP3a),
+    Rec2020a(Rec2020a),
+    A98Rgba(A98Rgba),
+    ProPhotoRgba(ProPhotoRgba),
+    Xyza(Xyza),
+    XyzaD50(XyzaD50),
+    Yxya(Yxya),
 }
 
 impl Color {
@@ -85,100 +344,748 @@ impl Color {
         from_hsl([f32; 3]) -> Hsla : Hsl a=1.0,
         from_hsva([f32; 4]) -> Hsva : Hsva,
         from_hsv([f32; 3]) -> Hsva : Hsv a=1.0,
+        from_cmyka([f32; 5]) -> Cmyka : Cmyka,
+        from_cmyk([f32; 4]) -> Cmyka : Cmyk a=1.0,
+        from_hsluva([f32; 4]) -> Hsluva : Hsluva,
+        from_hsluv([f32; 3]) -> Hsluva : Hsluv a=1.0,
+        from_hpluva([f32; 4]) -> Hpluva : Hpluva,
+        from_hpluv([f32; 3]) -> Hpluva : Hpluv a=1.0,
+        from_oklaba([f32; 4]) -> Oklaba : Oklaba,
+        from_oklab([f32; 3]) -> Oklaba : Oklab a=1.0,
+        from_oklcha([f32; 4]) -> Oklcha : Oklcha,
+        from_oklch([f32; 3]) -> Oklcha : Oklch a=1.0,
+        from_laba_d50([f32; 4]) -> LabaD50 : LabaD50,
+        from_lab_d50([f32; 3]) -> LabaD50 : LabD50 a=1.0,
+        from_lcha_d50([f32; 4]) -> LchaD50 : LchaD50,
+        from_lch_d50([f32; 3]) -> LchaD50 : LchD50 a=1.0,
+        from_laba_d65([f32; 4]) -> LabaD65 : LabaD65,
+        from_lab_d65([f32; 3]) -> LabaD65 : LabD65 a=1.0,
+        from_lcha_d65([f32; 4]) -> LchaD65 : LchaD65,
+        from_lch_d65([f32; 3]) -> LchaD65 : LchD65 a=1.0,
+        from_lin_srgba([f32; 4]) -> LinSrgba : LinSrgba,
+        from_lin_srgb([f32; 3]) -> LinSrgba : LinSrgb a=1.0,
+        from_display_p3a([f32; 4]) -> DisplayP3a : DisplayP3a,
+        from_display_p3([f32; 3]) -> DisplayP3a : DisplayP3 a=1.0,
+        from_rec2020a([f32; 4]) -> Rec2020a : Rec2020a,
+        from_rec2020([f32; 3]) -> Rec2020a : Rec2020 a=1.0,
+        from_a98_rgba([f32; 4]) -> A98Rgba : A98Rgba,
+        from_a98_rgb([f32; 3]) -> A98Rgba : A98Rgb a=1.0,
+        from_prophoto_rgba([f32; 4]) -> ProPhotoRgba : ProPhotoRgba,
+        from_prophoto_rgb([f32; 3]) -> ProPhotoRgba : ProPhotoRgb a=1.0,
+        from_xyza_d65([f32; 4]) -> Xyza : Xyza,
+        from_xyz_d65([f32; 3]) -> Xyza : Xyz a=1.0,
+        from_xyza_d50([f32; 4]) -> XyzaD50 : XyzaD50,
+        from_xyz_d50([f32; 3]) -> XyzaD50 : XyzD50 a=1.0,
+        from_xyya([f32; 4]) -> Yxya : Yxya,
+        from_xyy([f32; 3]) -> Yxya : Yxy a=1.0,
     );
 
+    /// Whiteness and blackness that add up to `1` or more are scaled
+    /// down to sum to `1`, which is the gray CSS renders them as
+    pub fn from_hwba([h, w, b, a]: [f32; 4]) -> Self {
+        let sum = w + b;
+        let [w, b] = match sum >= 1.0 {
+            true => [w / sum, b / sum],
+            false => [w, b],
+        };
+
+        Self::Hwba(Hwba::new(h, w, b, a))
+    }
+
+    pub fn from_hwb([h, w, b]: [f32; 3]) -> Self {
+        Self::from_hwba([h, w, b, 1.0])
+    }
+
     impl_from_color_map!(
-        from_argb([u8; 4]) -> from_rgba(3, 0, 1, 2),
-        from_fargb([f32; 4]) -> from_frgba(3, 0, 1, 2),
-        from_ahsl([f32; 4]) -> from_hsla(3, 0, 1, 2),
-        from_ahsv([f32; 4]) -> from_hsva(3, 0, 1, 2),
+        from_argb([u8; 4]) -> from_rgba(1, 2, 3, 0),
+        from_fargb([f32; 4]) -> from_frgba(1, 2, 3, 0),
+        from_ahsl([f32; 4]) -> from_hsla(1, 2, 3, 0),
+        from_ahsv([f32; 4]) -> from_hsva(1, 2, 3, 0),
+        from_ahwb([f32; 4]) -> from_hwba(1, 2, 3, 0),
     );
 
     impl_to_color_map!(
-        "ahex": to_ahex -> Rgba<u8, u8>(|r, g, b, a| "#{a:02X}{r:02X}{g:02X}{b:02X}"),
-        "hexa": to_hexa -> Rgba<u8, u8>(|r, g, b, a| "#{r:02X}{g:02X}{b:02X}{a:02X}"),
-        "hex": to_hex -> Rgb<u8>(|r, g, b| "#{r:02X}{g:02X}{b:02X}"),
-        "argb": to_argb -> Rgba<u8, u8>(|r, g, b, a| "{a}, {r}, {g}, {b}"),
-        "rgba": to_rgba -> Rgba<u8, u8>(|r, g, b, a| "{r}, {g}, {b}, {a}"),
-        "rgb": to_rgb -> Rgb<u8>(|r, g, b| "{r}, {g}, {b}"),
-        "fargb": to_fargb -> Srgba<f32, f32>(|r, g, b, a| "{a}, {r}, {g}, {b}"),
-        "frgba": to_frgba -> Srgba<f32, f32>(|r, g, b, a| "{r}, {g}, {b}, {a}"),
-        "frgb": to_frgb -> Srgb<u8>(|r, g, b| "{r}, {g}, {b}"),
-        "ahsl": to_ahsl -> Hsla<f32, f32>(|h, s, l, a| "{a}, {}, {s}, {l},", h.into_inner()),
-        "hsla": to_hsla -> Hsla<f32, f32>(|h, s, l, a| "{}, {s}, {l}, {a}", h.into_inner()),
-        "hsl": to_hsl -> Hsl<f32>(|h, s, l| "{}, {s}, {l}", h.into_inner()),
-        "ahsv": to_ahsv -> Hsva<f32, f32>(|h, s, v, a| "{a}, {}, {s}, {v}", h.into_inner()),
-        "hsva": to_hsva -> Hsva<f32, f32>(|h, s, v, a| "{}, {s}, {v}, {a}", h.into_inner()),
-        "hsv": to_hsv -> Hsv<f32>(|h, s, v| "{}, {s}, {v}", h.into_inner()),
+        "argb": to_argb -> Rgba<u8, u8>(|r, g, b, a| [a, r, g, b]),
+        "rgba": to_rgba -> Rgba<u8, u8>(|r, g, b, a| [r, g, b, a]),
+        "rgb": to_rgb -> Rgb<u8>(|r, g, b| [r, g, b]),
+        "fargb": to_fargb -> Srgba<f32, f32>(|r, g, b, a| [a, r, g, b]),
+        "frgba": to_frgba -> Srgba<f32, f32>(|r, g, b, a| [r, g, b, a]),
+        "frgb": to_frgb -> Srgb<f32>(|r, g, b| [r, g, b]),
+        "x11": to_x11 -> Rgb<u8>(|r, g, b| "rgb:{r:02x}/{g:02x}/{b:02x}"),
+        "x11-16": to_x11_16 -> Rgb<u16>(|r, g, b| "rgb:{r:04x}/{g:04x}/{b:04x}"),
+        "swift-uicolor": to_swift_uicolor -> Srgba<f32, f32>(|r, g, b, a| "UIColor(red: {r:.3}, green: {g:.3}, blue: {b:.3}, alpha: {a:.3})"),
+        "swift-color": to_swift_color -> Srgba<f32, f32>(|r, g, b, a| "Color(.sRGB, red: {r:.3}, green: {g:.3}, blue: {b:.3}, opacity: {a:.3})"),
+        "kotlin-color" | "compose-color": to_kotlin_color -> Rgba<u8, u8>(|r, g, b, a| "Color(0x{a:02X}{r:02X}{g:02X}{b:02X})"),
+        "flutter-color": to_flutter_color -> Rgba<u8, u8>(|r, g, b, a| "Color(0x{a:02X}{r:02X}{g:02X}{b:02X})"),
+        "csharp-color": to_csharp_color -> Rgba<u8, u8>(|r, g, b, a| "Color.FromArgb({a}, {r}, {g}, {b})"),
+        "rust-rgba": to_rust_rgba -> Rgba<u8, u8>(|r, g, b, a| "Rgba({r}, {g}, {b}, {a})"),
+        "rust-rgb": to_rust_rgb -> Rgb<u8>(|r, g, b| "Rgb({r}, {g}, {b})"),
+        "ahsl": to_ahsl -> Hsla<f32, f32>(|h, s, l, a| [a, h.into_inner(), s, l]),
+        "hsla": to_hsla -> Hsla<f32, f32>(|h, s, l, a| [h.into_inner(), s, l, a]),
+        "hsl": to_hsl -> Hsl<f32>(|h, s, l| [h.into_inner(), s, l]),
+        "ahsv": to_ahsv -> Hsva<f32, f32>(|h, s, v, a| [a, h.into_inner(), s, v]),
+        "hsva": to_hsva -> Hsva<f32, f32>(|h, s, v, a| [h.into_inner(), s, v, a]),
+        "hsv": to_hsv -> Hsv<f32>(|h, s, v| [h.into_inner(), s, v]),
+        "ahwb": to_ahwb -> Hwba<f32, f32>(|h, w, b, a| [a, h.into_inner(), w, b]),
+        "hwba": to_hwba -> Hwba<f32, f32>(|h, w, b, a| [h.into_inner(), w, b, a]),
+        "hwb": to_hwb -> Hwb<f32>(|h, w, b| [h.into_inner(), w, b]),
+        "cmyka": to_cmyka -> Cmyka(|c, m, y, k, a| [c, m, y, k, a]),
+        "cmyk": to_cmyk -> Cmyk(|c, m, y, k| [c, m, y, k]),
+        "hsluva": to_hsluva -> Hsluva(|h, s, l, a| [h.into_positive_degrees(), s, l, a]),
+        "hsluv": to_hsluv -> Hsluv(|h, s, l| [h.into_positive_degrees(), s, l]),
+        "hpluva": to_hpluva -> Hpluva(|h, s, l, a| [h, s, l, a]),
+        "hpluv": to_hpluv -> Hpluv(|h, s, l| [h, s, l]),
+        "oklaba": to_oklaba -> Oklaba(|l, a, b, alpha| [l, a, b, alpha]),
+        "oklab": to_oklab -> Oklab(|l, a, b| [l, a, b]),
+        "oklcha": to_oklcha -> Oklcha(|l, c, h, a| [l, c, h.into_inner(), a]),
+        "oklch": to_oklch -> Oklch(|l, c, h| [l, c, h.into_inner()]),
+        "laba" | "laba-d50": to_laba_d50 -> LabaD50(|l, a, b, alpha| [l, a, b, alpha]),
+        "lab" | "lab-d50": to_lab_d50 -> LabD50(|l, a, b| [l, a, b]),
+        "lcha" | "lcha-d50": to_lcha_d50 -> LchaD50(|l, c, h, a| [l, c, h.into_inner(), a]),
+        "lch" | "lch-d50": to_lch_d50 -> LchD50(|l, c, h| [l, c, h.into_inner()]),
+        "laba-d65": to_laba_d65 -> LabaD65(|l, a, b, alpha| [l, a, b, alpha]),
+        "lab-d65": to_lab_d65 -> LabD65(|l, a, b| [l, a, b]),
+        "lcha-d65": to_lcha_d65 -> LchaD65(|l, c, h, a| [l, c, h.into_inner(), a]),
+        "lch-d65": to_lch_d65 -> LchD65(|l, c, h| [l, c, h.into_inner()]),
+        "srgb-lineara": to_lin_srgba -> LinSrgba(|r, g, b, a| [r, g, b, a]),
+        "srgb-linear": to_lin_srgb -> LinSrgb(|r, g, b| [r, g, b]),
+        "display-p3a": to_display_p3a -> DisplayP3a(|r, g, b, a| [r, g, b, a]),
+        "display-p3": to_display_p3 -> DisplayP3(|r, g, b| [r, g, b]),
+        "rec2020a": to_rec2020a -> Rec2020a(|r, g, b, a| [r, g, b, a]),
+        "rec2020": to_rec2020 -> Rec2020(|r, g, b| [r, g, b]),
+        "a98-rgba": to_a98_rgba -> A98Rgba(|r, g, b, a| [r, g, b, a]),
+        "a98-rgb": to_a98_rgb -> A98Rgb(|r, g, b| [r, g, b]),
+        "prophoto-rgba": to_prophoto_rgba -> ProPhotoRgba(|r, g, b, a| [r, g, b, a]),
+        "prophoto-rgb": to_prophoto_rgb -> ProPhotoRgb(|r, g, b| [r, g, b]),
+        "xyza" | "xyza-d65": to_xyza_d65 -> Xyza(|x, y, z, a| [x, y, z, a]),
+        "xyz" | "xyz-d65": to_xyz_d65 -> Xyz(|x, y, z| [x, y, z]),
+        "xyza-d50": to_xyza_d50 -> XyzaD50(|x, y, z, a| [x, y, z, a]),
+        "xyz-d50": to_xyz_d50 -> XyzD50(|x, y, z| [x, y, z]),
+        "xyya": to_xyya -> Yxya(|x, y, luma, a| [x, y, luma, a]),
+        "xyy": to_xyy -> Yxy(|x, y, luma| [x, y, luma]),
+        "kelvin": to_kelvin -> Yxy(|x, y, luma| "{}K", kelvin::cct(Yxy::new(x, y, luma)).round()),
     );
 }
 
-impl_color!(Srgba);
-impl_color!(Rgb);
-impl_color!(Hsl);
-impl_color!(Hsla);
-impl_color!(Hsv);
-impl_color!(Hsva);
+impl_color!(Srgba: Rgba, gamut = Srgb);
+impl_color!(Rgb: Rgba, gamut = Srgb);
+impl_color!(LinSrgba: LinSrgba => Srgba: LinSrgba; gamut = Srgb);
+impl_color!(LinSrgb: LinSrgba => Srgba: LinSrgba; gamut = Srgb);
+impl_color!(Hsl: Hsla, gamut = Srgb);
+impl_color!(Hsla: Hsla, gamut = Srgb);
+impl_color!(Hsv: Hsva, gamut = Srgb);
+impl_color!(Hsva: Hsva, gamut = Srgb);
+impl_color!(Hwb: Hwba, gamut = Srgb);
+impl_color!(Hwba: Hwba, gamut = Srgb);
+impl_color!(Hsluv: Hsluva, gamut = Srgb);
+impl_color!(Hsluva: Hsluva, gamut = Srgb);
+impl_color!(Oklab: Oklaba);
+impl_color!(Oklaba: Oklaba);
+impl_color!(Oklch: Oklcha);
+impl_color!(Oklcha: Oklcha);
+impl_color!(Xyza: Xyza);
+impl_color!(Xyz: Xyza);
+impl_color!(Yxya: Yxya);
+impl_color!(Yxy: Yxya);
+impl_color!(LabD65: LabaD65);
+impl_color!(LabaD65: LabaD65);
+impl_color!(LchD65: LchaD65);
+impl_color!(LchaD65: LchaD65);
+impl_color!(LabD50: LabaD50 => XyzaD50: LabaD50, LchaD50);
+impl_color!(LabaD50: LabaD50 => XyzaD50: LabaD50, LchaD50);
+impl_color!(LchD50: LchaD50 => XyzaD50: LabaD50, LchaD50);
+impl_color!(LchaD50: LchaD50 => XyzaD50: LabaD50, LchaD50);
+impl_color!(XyzD50: XyzaD50 => XyzaD50: XyzaD50);
+impl_color!(ProPhotoRgba: ProPhotoRgba => XyzaD50: ProPhotoRgba; gamut = ProPhotoRgb);
+impl_color!(ProPhotoRgb: ProPhotoRgba => XyzaD50: ProPhotoRgba; gamut = ProPhotoRgb);
+impl_color!(DisplayP3a: DisplayP3a => Xyza: DisplayP3a; gamut = DisplayP3);
+impl_color!(DisplayP3: DisplayP3a => Xyza: DisplayP3a; gamut = DisplayP3);
+impl_color!(Rec2020a: Rec2020a => Xyza: Rec2020a; gamut = Rec2020);
+impl_color!(Rec2020: Rec2020a => Xyza: Rec2020a; gamut = Rec2020);
+impl_color!(A98Rgba: A98Rgba => Xyza: A98Rgba; gamut = A98Rgb);
+impl_color!(A98Rgb: A98Rgba => Xyza: A98Rgba; gamut = A98Rgb);
+
+impl_color!(@gamut XyzaD50);
+impl_color!(@gamut Cmyka, Srgb);
+impl_color!(@gamut Cmyk, Srgb);
+impl_color!(@gamut Hpluva, Srgb);
+impl_color!(@gamut Hpluv, Srgb);
+
+impl From<Color> for Cmyka {
+    fn from(color: Color) -> Cmyka {
+        match color {
+            Color::Cmyka(color) => color,
+            color => Srgba::from(color).into(),
+        }
+    }
+}
+
+impl From<Color> for Cmyk {
+    fn from(color: Color) -> Cmyk {
+        Cmyka::from(color).without_alpha()
+    }
+}
+
+impl From<Color> for Hpluva {
+    fn from(color: Color) -> Hpluva {
+        match color {
+            Color::Hpluva(color) => color,
+            color => Lchuva::from_color_unclamped(Xyza::from(color)).into(),
+        }
+    }
+}
+
+impl From<Color> for Hpluv {
+    fn from(color: Color) -> Hpluv {
+        Hpluva::from(color).without_alpha()
+    }
+}
+
+// the hub for every D50 based format
+impl From<Color> for XyzaD50 {
+    fn from(color: Color) -> XyzaD50 {
+        match color {
+            Color::LabaD50(color) => color.into_color(),
+            Color::LchaD50(color) => color.into_color(),
+            Color::XyzaD50(color) => color,
+            Color::ProPhotoRgba(color) => XyzaD50::from_color_unclamped(color),
+            color => adapt::<_, Xyz<D50>, D65, D50>(Xyza::from(color)),
+        }
+    }
+}
+
+/// Serialized as [`Color::CANONICAL_FORMAT`], see [`serde_with`] and [`Color::serialize_as`] for other formats
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize_as(Self::CANONICAL_FORMAT, serializer)
+    }
+}
+
+/// Same as [`Color::from_str`] without a palette
+impl FromStr for Color {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Color::from_str(s, None)
+    }
+}
+
+/// Formatted as [`Color::CANONICAL_FORMAT`]
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let color = self
+            .clone()
+            .to_format(Self::CANONICAL_FORMAT)
+            .map_err(|_| fmt::Error)?;
+
+        f.write_str(&color)
+    }
+}
+
+/// Colors are equal when they're the same in Oklab, regardless of the space they're
+/// authored in, see [`Color::approx_eq`] for a custom tolerance
+impl PartialEq for Color {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical() == other.canonical()
+    }
+}
+
+impl Eq for Color {}
+
+impl Hash for Color {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical().hash(state);
+    }
+}
+
+impl_color_from!(
+    Srgb => Srgb,
+    Srgba => Rgba,
+    Hsl => Hsla,
+    Hsla => Hsla,
+    Hsv => Hsva,
+    Hsva => Hsva,
+    Hwb => Hwba,
+    Hwba => Hwba,
+    Cmyk => Cmyka,
+    Cmyka => Cmyka,
+    Hsluv => Hsluva,
+    Hsluva => Hsluva,
+    Hpluv => Hpluva,
+    Hpluva => Hpluva,
+    Oklab => Oklaba,
+    Oklaba => Oklaba,
+    Oklch => Oklcha,
+    Oklcha => Oklcha,
+    LabD50 => LabaD50,
+    LabaD50 => LabaD50,
+    LchD50 => LchaD50,
+    LchaD50 => LchaD50,
+    LabD65 => LabaD65,
+    LabaD65 => LabaD65,
+    LchD65 => LchaD65,
+    LchaD65 => LchaD65,
+    LinSrgb => LinSrgba,
+    LinSrgba => LinSrgba,
+    DisplayP3 => DisplayP3a,
+    DisplayP3a => DisplayP3a,
+    Rec2020 => Rec2020a,
+    Rec2020a => Rec2020a,
+    A98Rgb => A98Rgba,
+    A98Rgba => A98Rgba,
+    ProPhotoRgb => ProPhotoRgba,
+    ProPhotoRgba => ProPhotoRgba,
+    Xyz => Xyza,
+    Xyza => Xyza,
+    XyzD50 => XyzaD50,
+    XyzaD50 => XyzaD50,
+    Yxy => Yxya,
+    Yxya => Yxya,
+);
+
+impl From<Srgb<u8>> for Color {
+    fn from(color: Srgb<u8>) -> Color {
+        Color::Srgb(color.into_format())
+    }
+}
+
+impl From<Srgba<u8>> for Color {
+    fn from(color: Srgba<u8>) -> Color {
+        Color::Rgba(color.into_format())
+    }
+}
+
+/// Deserialized from any string [`Color::from_str`] accepts, without a palette
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let src = Cow::<str>::deserialize(deserializer)?;
+
+        Self::from_str(&src, None).map_err(de::Error::custom)
+    }
+}
+
+macro_rules! impl_serde_with {
+    ($($module:ident: $format:literal),+ $(,)?) => {
+        $(#[doc = concat!("Serializes as `", $format, "`")]
+        pub mod $module {
+            use crate::color::Color;
+            use serde::{Deserialize, Deserializer, Serializer};
+
+            pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+                color.serialize_as($format, serializer)
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+                Color::deserialize(deserializer)
+            }
+        })+
+    };
+}
+
+/// Modules for `#[serde(with = "colorutil::color::serde_with::hex")]` on a [`Color`] field,
+/// to serialize it in another format than [`Color::CANONICAL_FORMAT`].
+/// Deserializing accepts anything [`Color::from_str`] does
+pub mod serde_with {
+    impl_serde_with!(
+        lossless: "lossless",
+        hex: "hexa-auto",
+        css_rgb: "css-rgb",
+        css_hsl: "css-hsl",
+        css_lab: "css-lab",
+        css_oklch: "css-oklch",
+    );
+}
 
 impl Color {
+    /// Format colors are serialized in, see [`Color::to_lossless`]
+    pub const CANONICAL_FORMAT: &str = "lossless";
+
+    /// Serializes the color as a string in any output format, for use
+    /// in a `#[serde(serialize_with = "...")]` function
+    pub fn serialize_as<S: Serializer>(
+        &self,
+        format: &str,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let color = self.clone().to_format(format).map_err(ser::Error::custom)?;
+
+        serializer.serialize_str(&color)
+    }
+
+    /// The color in the space it was authored in with every component at full precision,
+    /// `oklch(0.7 0.1 20)` or `color(display-p3 1 0 0 / 0.5)`. 8-bit sRGB is emitted as
+    /// hex, alpha only when the color isn't opaque. [`Color::from_str`] parses it back
+    /// into the same color
+    pub fn to_lossless(&self) -> String {
+        match self {
+            Self::Srgb(c) => srgb_lossless(c.red, c.green, c.blue, 1.0),
+            Self::Rgba(c) => srgb_lossless(c.red, c.green, c.blue, c.alpha),
+            Self::Hsla(c) => legacy_lossless(
+                "hsla(",
+                &[c.hue.into_inner(), c.saturation, c.lightness],
+                c.alpha,
+            ),
+            Self::Hsva(c) => legacy_lossless(
+                "hsva(",
+                &[c.hue.into_inner(), c.saturation, c.value],
+                c.alpha,
+            ),
+            Self::Hwba(c) => legacy_lossless(
+                "hwba(",
+                &[c.hue.into_inner(), c.whiteness, c.blackness],
+                c.alpha,
+            ),
+            Self::Cmyka(c) => lossless("cmyk(", &[c.cyan, c.magenta, c.yellow, c.black], c.alpha),
+            Self::Hsluva(c) => {
+                lossless("hsluv(", &[c.hue.into_inner(), c.saturation, c.l], c.alpha)
+            }
+            Self::Hpluva(c) => lossless("hpluv(", &[c.hue, c.saturation, c.l], c.alpha),
+            Self::Oklaba(c) => lossless("oklab(", &[c.l, c.a, c.b], c.alpha),
+            Self::Oklcha(c) => lossless("oklch(", &[c.l, c.chroma, c.hue.into_inner()], c.alpha),
+            Self::LabaD50(c) => lossless("lab(", &[c.l, c.a, c.b], c.alpha),
+            Self::LchaD50(c) => lossless("lch(", &[c.l, c.chroma, c.hue.into_inner()], c.alpha),
+            Self::LabaD65(c) => lossless("lab-d65(", &[c.l, c.a, c.b], c.alpha),
+            Self::LchaD65(c) => lossless("lch-d65(", &[c.l, c.chroma, c.hue.into_inner()], c.alpha),
+            Self::LinSrgba(c) => lossless("color(srgb-linear ", &[c.red, c.green, c.blue], c.alpha),
+            Self::DisplayP3a(c) => {
+                lossless("color(display-p3 ", &[c.red, c.green, c.blue], c.alpha)
+            }
+            Self::Rec2020a(c) => lossless("color(rec2020 ", &[c.red, c.green, c.blue], c.alpha),
+            Self::A98Rgba(c) => lossless("color(a98-rgb ", &[c.red, c.green, c.blue], c.alpha),
+            Self::ProPhotoRgba(c) => {
+                lossless("color(prophoto-rgb ", &[c.red, c.green, c.blue], c.alpha)
+            }
+            Self::Xyza(c) => lossless("color(xyz-d65 ", &[c.x, c.y, c.z], c.alpha),
+            Self::XyzaD50(c) => lossless("color(xyz-d50 ", &[c.x, c.y, c.z], c.alpha),
+            Self::Yxya(c) => lossless("xyy(", &[c.x, c.y, c.luma], c.alpha),
+        }
+    }
+
+    /// Whether the Oklab distance of the colors and the difference of their alpha
+    /// are both within `tolerance`, `0.02` is about a just noticeable difference
+    pub fn approx_eq(&self, other: &Color, tolerance: f32) -> bool {
+        let a = Oklaba::from(self.clone());
+        let b = Oklaba::from(other.clone());
+
+        gamut::delta_eok(a.color, b.color) <= tolerance && (a.alpha - b.alpha).abs() <= tolerance
+    }
+
+    /// Oklab quantized to steps far below a visible difference, what equality and hashing use
+    fn canonical(&self) -> [i32; 4] {
+        let (l, a, b, alpha) = Oklaba::from(self.clone()).into_components();
+
+        [l, a, b, alpha].map(|c| (c * CANONICAL_STEPS).round() as i32)
+    }
+
+    /// Brings the color into the gamut of `G`, colors that already are in gamut stay untouched
+    pub fn map_gamut<G: Gamut>(self, mapping: GamutMapping) -> Self {
+        let Alpha { color, alpha } = Oklaba::from(self.clone());
+
+        if G::from_oklab(color).is_in_gamut() {
+            return self;
+        }
+
+        gamut::map::<G>(color, mapping).into_color_with_alpha(alpha)
+    }
+
+    pub fn to_ahex(self) -> String {
+        self.to_hex_format(HexFormat::new(HexAlpha::Argb), GamutMapping::default())
+    }
+
+    pub fn to_hexa(self) -> String {
+        self.to_hex_format(HexFormat::new(HexAlpha::Rgba), GamutMapping::default())
+    }
+
+    pub fn to_hex(self) -> String {
+        self.to_hex_format(HexFormat::new(HexAlpha::None), GamutMapping::default())
+    }
+
+    /// Hex output with modifiers, see [`HexFormat`]
+    pub fn to_hex_format(self, hex: HexFormat, mapping: GamutMapping) -> String {
+        hex.format(self.into_srgba_u8(mapping))
+    }
+
+    /// ANSI SGR escape sequence, see [`Ansi`]
+    pub fn to_ansi(self, ansi: Ansi, mapping: GamutMapping) -> String {
+        ansi.format(self.into_srgba_u8(mapping).color)
+    }
+
+    /// Color packed into a `u32`, see [`Packing`]
+    pub fn to_packed(self, packing: Packing, mapping: GamutMapping) -> u32 {
+        packing.pack(self.into_srgba_u8(mapping))
+    }
+
+    /// Nearest named color of the databases, see [`names::nearest_name`]
+    pub fn to_name(
+        self,
+        exact: bool,
+        mapping: GamutMapping,
+        databases: &[NameDatabase],
+    ) -> Option<&str> {
+        names::nearest_name(self.into_srgba_u8(mapping).color, exact, databases)
+    }
+
+    fn into_srgba_u8(self, mapping: GamutMapping) -> Srgba<u8> {
+        let color = <Srgba as TargetGamut>::map_gamut(self, mapping);
+
+        Srgba::from(color).into_format()
+    }
+
+    /// Formats that are built from modifiers rather than listed in the format table,
+    /// and the user defined formats of the config.
+    /// Flags that are understood by the format are removed from `params`
+    fn format_dynamic(
+        self,
+        name: &str,
+        params: &mut FormatParams,
+        options: &FormatOptions,
+    ) -> Option<Result<String>> {
+        let mapping = options.mapping;
+
+        if let Some(mut hex) = HexFormat::parse(name) {
+            params.flags.retain(|flag| hex.modifier(flag).is_none());
+
+            // the case only applies to the digits, not to a `0x` prefix
+            if let Some(case) = params.case.take() {
+                hex.lowercase = case == Case::Lower;
+            }
+
+            return Some(Ok(self.to_hex_format(hex, mapping)));
+        }
+
+        if let Some(packing) = Packing::from_name(name) {
+            return Some(Ok(self.to_packed(packing, mapping).to_string()));
+        }
+
+        if let Some(ansi) = Ansi::from_name(name) {
+            return Some(Ok(self.to_ansi(ansi, mapping)));
+        }
+
+        if name == "lossless" {
+            return Some(Ok(self.to_lossless()));
+        }
+
+        if name == "name" {
+            let exact = params.flags.iter().any(|flag| flag == "exact");
+            params.flags.retain(|flag| flag != "exact");
+
+            let databases = options.names.unwrap_or(DEFAULT_NAMES);
+            let name = self
+                .clone()
+                .to_name(exact, mapping, databases)
+                .ok_or_else(|| Error::NoNamedColor(self.to_hex()));
+
+            return Some(name.map(str::to_owned));
+        }
+
+        if let Some(shader) = Shader::from_name(name) {
+            return Some(Ok(shader.format(self, params, mapping)));
+        }
+
+        if let Some(css) = Css::from_name(name) {
+            return Some(Ok(css.format(self, params, mapping)));
+        }
+
+        let template = options.formats?.get(name)?;
+
+        Some(Template::parse(template).map(|template| template.render(self, params, mapping)))
+    }
+
     pub fn from_str(s: &str, palette: Option<&Palette>) -> Result<Self, Error> {
-        impl_match_color!(s,
-            "argb": from_argb<u8, 4>,
-            "rgba": from_rgba<u8, 4>,
-            "rgb": from_rgb<u8, 3>,
-            "fargb": from_fargb<f32, 4>,
-            "frgba": from_frgba<f32, 4>,
-            "frgb": from_frgb<f32, 3>,
-            "ahsl": from_ahsl<f32, 4>,
-            "hsla": from_hsla<f32, 4>,
-            "hsl": from_hsl<f32, 3>,
-            "ahsv": from_ahsv<f32, 4>,
-            "hsva": from_hsva<f32, 4>,
-            "hsv": from_hsv<f32, 3>,
-        );
+        Self::from_str_with(s, palette, DEFAULT_NAMES)
+    }
+
+    /// Like [`Color::from_str`] but names are looked up in `names` instead of only CSS
+    pub fn from_str_with(
+        s: &str,
+        palette: Option<&Palette>,
+        names: &[NameDatabase],
+    ) -> Result<Self, Error> {
+        if s.eq_ignore_ascii_case("transparent") {
+            return Ok(Self::from_rgba([0, 0, 0, 0]));
+        }
+
+        if let Some(rgba) = packed::parse(s) {
+            return Ok(Self::Rgba(rgba?.into_format()));
+        }
+
+        if let Some(xyz) = kelvin::parse(s) {
+            return Ok(Self::Xyza(xyz?.into()));
+        }
+
+        if let Some(spec) = s.strip_prefix("rgb:") {
+            return parse_x11_rgb(s, spec).map(Self::from_frgb);
+        }
+
+        if let Some(f) = Function::parse(s)? {
+            if let Some(space) = f.space() {
+                let spaces = impl_match_color!(f, space,
+                    "srgb": from_frgb<f32>[COORD, COORD, COORD] / from_frgba[UNIT],
+                    "srgb-linear": from_lin_srgb<f32>[COORD, COORD, COORD] / from_lin_srgba[UNIT],
+                    "display-p3": from_display_p3<f32>[COORD, COORD, COORD] / from_display_p3a[UNIT],
+                    "rec2020": from_rec2020<f32>[COORD, COORD, COORD] / from_rec2020a[UNIT],
+                    "a98-rgb": from_a98_rgb<f32>[COORD, COORD, COORD] / from_a98_rgba[UNIT],
+                    "prophoto-rgb": from_prophoto_rgb<f32>[COORD, COORD, COORD] / from_prophoto_rgba[UNIT],
+                    "xyz" | "xyz-d65": from_xyz_d65<f32>[COORD, COORD, COORD] / from_xyza_d65[UNIT],
+                    "xyz-d50": from_xyz_d50<f32>[COORD, COORD, COORD] / from_xyza_d50[UNIT],
+                );
+
+                return Err(f.unknown_space(spaces));
+            }
+
+            // the legacy comma syntax keeps its `0..=1` numbers
+            let percent = if f.is_legacy() { UNIT } else { HSL_PERCENT };
+
+            let functions = impl_match_color!(f, f.name(),
+                "argb": from_fargb<f32>[CHANNEL, CHANNEL, CHANNEL, CHANNEL],
+                "rgb" | "rgba": from_frgb<f32>[CHANNEL, CHANNEL, CHANNEL] / from_frgba[CHANNEL],
+                "fargb": from_fargb<f32>[UNIT, UNIT, UNIT, UNIT],
+                "frgb" | "frgba": from_frgb<f32>[UNIT, UNIT, UNIT] / from_frgba[UNIT],
+                "ahsl": from_ahsl<f32>[UNIT, HUE, UNIT, UNIT],
+                "hsl" | "hsla": from_hsl<f32>[HUE, percent, percent] / from_hsla[UNIT],
+                "ahsv": from_ahsv<f32>[UNIT, HUE, UNIT, UNIT],
+                "hsv" | "hsva": from_hsv<f32>[HUE, percent, percent] / from_hsva[UNIT],
+                "ahwb": from_ahwb<f32>[UNIT, HUE, UNIT, UNIT],
+                "hwb" | "hwba": from_hwb<f32>[HUE, percent, percent] / from_hwba[UNIT],
+                "cmyk" | "cmyka" | "device-cmyk": from_cmyk<f32>[UNIT, UNIT, UNIT, UNIT] / from_cmyka[UNIT],
+                "hsluv" | "hsluva": from_hsluv<f32>[HUE, HSLUV_SL, HSLUV_SL] / from_hsluva[UNIT],
+                "hpluv" | "hpluva": from_hpluv<f32>[HUE, HSLUV_SL, HSLUV_SL] / from_hpluva[UNIT],
+                "oklab" | "oklaba": from_oklab<f32>[UNIT, OKLAB_AB, OKLAB_AB] / from_oklaba[UNIT],
+                "oklch" | "oklcha": from_oklch<f32>[UNIT, OKLCH_C, HUE] / from_oklcha[UNIT],
+                "lab" | "laba" | "lab-d50" | "laba-d50": from_lab_d50<f32>[LAB_L, LAB_AB, LAB_AB] / from_laba_d50[UNIT],
+                "lch" | "lcha" | "lch-d50" | "lcha-d50": from_lch_d50<f32>[LAB_L, LCH_C, HUE] / from_lcha_d50[UNIT],
+                "lab-d65" | "laba-d65": from_lab_d65<f32>[LAB_L, LAB_AB, LAB_AB] / from_laba_d65[UNIT],
+                "lch-d65" | "lcha-d65": from_lch_d65<f32>[LAB_L, LCH_C, HUE] / from_lcha_d65[UNIT],
+                "xyz" | "xyza" | "xyz-d65" | "xyza-d65": from_xyz_d65<f32>[COORD, COORD, COORD] / from_xyza_d65[UNIT],
+                "xyz-d50" | "xyza-d50": from_xyz_d50<f32>[COORD, COORD, COORD] / from_xyza_d50[UNIT],
+                "xyy" | "xyya": from_xyy<f32>[UNIT, XYY_Y, COORD] / from_xyya[UNIT],
+            );
+
+            return Err(f.unknown_name(functions));
+        }
 
         if s.starts_with('#') {
             let rgba = Rgba::from_str(s)
                 .or_else(|_| Rgb::from_str(s).map(Into::into))
-                .map_err(|_| Error::FailedToParseColor(s.to_owned()))?;
+                .map_err(|_| ParseError::expected(s, 1, "3, 4, 6 or 8 hex digits"))?;
 
             return Ok(Self::Rgba(rgba.into()));
         }
 
-        if let Some(palette) = palette {
-            if s.starts_with('$') {
-                let s = palette
-                    .get(s.trim_start_matches('$'))
-                    .ok_or_else(|| Error::FailedToParseColor(s.to_owned()))?;
+        if let Some(palette) = palette
+            && s.starts_with('$')
+        {
+            let name = s.trim_start_matches('$');
+            let s = palette.get(name).ok_or_else(|| {
+                let known = palette.keys().map(|key| key.as_ref());
 
-                return Self::from_str(s, Some(palette));
-            }
+                ParseError::unknown(s, s.len() - name.len(), "palette color", name, known)
+            })?;
+
+            return Self::from_str_with(s, Some(palette), names);
         }
 
-        palette::named::from_str(s)
+        names
+            .iter()
+            .find_map(|database| database.get(s))
             .map(Srgb::into)
             .map(Self::Srgb)
-            .ok_or_else(|| Error::FailedToParseColor(s.to_owned()))
+            .ok_or_else(|| {
+                let known = names
+                    .iter()
+                    .flat_map(|database| database.colors().map(|(name, _)| name));
+
+                ParseError::unknown(s, 0, "color name", s, known).into()
+            })
+    }
+}
+
+/// `function` is everything up to the first component, like `oklch(` or `color(display-p3 `
+fn lossless(function: &str, components: &[f32], alpha: f32) -> String {
+    // `{}` is the shortest representation that parses back into the same `f32`
+    let components = components
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    match alpha == 1.0 {
+        true => format!("{function}{components})"),
+        false => format!("{function}{components} / {alpha})"),
     }
 }
 
-pub fn parse_params<T: FromStr, const N: usize>(text: &str) -> Result<[T; N]> {
-    let start = text
-        .find("(")
-        .ok_or_else(|| Error::FailedToParseColorParams(text.to_owned()))?;
-    let end = text
-        .find(")")
-        .ok_or_else(|| Error::FailedToParseColorParams(text.to_owned()))?;
+/// Comma syntax for `hsl()`, `hsv()` and `hwb()`, whose bare numbers are
+/// only `0..=1` in it, alpha is always given
+fn legacy_lossless(function: &str, components: &[f32], alpha: f32) -> String {
+    let components = components
+        .iter()
+        .chain([&alpha])
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("{function}{components})")
+}
 
-    let result = text[start + 1..end]
-        .split(",")
-        .map(str::trim)
-        .map(str::parse)
-        .flatten()
-        .collect_array::<N>()
-        .ok_or_else(|| Error::FailedToParseColorParams(text.to_owned()))?;
+/// Hex when every channel is exactly 8-bit, `color(srgb ...)` otherwise
+fn srgb_lossless(r: f32, g: f32, b: f32, alpha: f32) -> String {
+    let channels = [r, g, b, alpha];
+    let exact = channels
+        .iter()
+        .all(|&c| (0.0..=1.0).contains(&c) && (c * 255.0).round() / 255.0 == c);
 
-    Ok(result)
+    match exact {
+        true => HexFormat::parse("hexa-auto")
+            .expect("is a hex format")
+            .format(Srgba::new(r, g, b, alpha).into_format()),
+        false => lossless("color(srgb ", &[r, g, b], alpha),
+    }
+}
+
+/// `spec` is the part of `src` after `rgb:`, each channel has 1 to 4 hex digits
+fn parse_x11_rgb(src: &str, spec: &str) -> Result<[f32; 3]> {
+    let channel = |c: &str| {
+        if !(1..=4).contains(&c.len()) || !c.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let value = u16::from_str_radix(c, 16).ok()?;
+        let max = (1u32 << (4 * c.len())) - 1;
+
+        Some(f32::from(value) / max as f32)
+    };
+
+    let mut parts = spec.split('/');
+    let mut channels = [0.0; 3];
+
+    for value in &mut channels {
+        let Some(part) = parts.next() else {
+            return Err(ParseError::expected(src, src.len(), "`/`").into());
+        };
+
+        *value = channel(part)
+            .ok_or_else(|| ParseError::expected(src, offset_of(src, part), "1 to 4 hex digits"))?;
+    }
+
+    // the slash before the extra channel
+    if let Some(part) = parts.next() {
+        return Err(ParseError::expected(src, offset_of(src, part) - 1, "end of input").into());
+    }
+
+    Ok(channels)
+}
+
+fn adapt<S, D, Swp, Dwp>(color: Alpha<S, f32>) -> Alpha<D, f32>
+where
+    D: AdaptFrom<S, Swp, Dwp, f32>,
+    Swp: WhitePoint<f32>,
+    Dwp: WhitePoint<f32>,
+{
+    Alpha {
+        color: D::adapt_from(color.color),
+        alpha: color.alpha,
+    }
 }
 
 pub fn parse_format<'a>(src_color: &'a str, format: &'a str, palette: Option<&Palette>) -> Result<String> {
@@ -192,6 +1099,7 @@ pub fn parse_text(
     prefix: impl AsRef<str>,
     suffix: impl AsRef<str>,
     palette: &Palette,
+    options: &FormatOptions,
 ) -> Result<String> {
     let prefix = prefix.as_ref();
     let suffix = suffix.as_ref();
@@ -205,7 +1113,7 @@ pub fn parse_text(
         let start = offset + start;
         let end = src[start..]
             .find(suffix)
-            .ok_or_else(|| Error::FailedToFindSuffix(start))?;
+            .ok_or(Error::FailedToFindSuffix(start))?;
 
         let value = &src[start + prefix.len()..start + end];
         let (name, format) = value
@@ -216,7 +1124,9 @@ pub fn parse_text(
             .get(name)
             .ok_or_else(|| Error::FailedToGetColor(value.to_owned()))?;
 
-        let color = parse_format(color, format, Some(palette))?;
+        let color =
+            Color::from_str_with(color, Some(palette), options.names.unwrap_or(DEFAULT_NAMES))?
+                .to_format_with(format, options)?;
 
         dst.push_str(&src[offset..start]);
         dst.push_str(&color);
@@ -227,3 +1137,607 @@ pub fn parse_text(
 
     Ok(dst)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Formats;
+
+    fn assert_close<const N: usize>(actual: [f32; N], expected: [f32; N], tolerance: f32) {
+        let close = actual
+            .iter()
+            .zip(&expected)
+            .all(|(a, e)| (a - e).abs() <= tolerance);
+        assert!(
+            close,
+            "{actual:?} is not within {tolerance} of {expected:?}"
+        );
+    }
+
+    fn srgb(color: Color) -> [f32; 3] {
+        let (r, g, b) = Srgb::from(color).into_components();
+        [r, g, b]
+    }
+
+    fn rgba(src: &str) -> [f32; 4] {
+        let (r, g, b, a) = Srgba::from(Color::from_str(src, None).unwrap()).into_components();
+        [r, g, b, a]
+    }
+
+    #[test]
+    fn oklab_of_srgb_red() {
+        let (l, a, b) = Oklab::from(Color::from_rgb([255, 0, 0])).into_components();
+        assert_close([l, a, b], [0.627_955, 0.224_863, 0.125_846], 1e-4);
+    }
+
+    #[test]
+    fn oklch_into_srgb() {
+        let color = Color::from_str("oklch(0.627955, 0.257683, 29.2339)", None).unwrap();
+        assert_close(srgb(color), [1.0, 0.0, 0.0], 1e-3);
+    }
+
+    #[test]
+    fn oklab_format() {
+        let oklab = Color::from_str("white", None)
+            .unwrap()
+            .to_format("oklab")
+            .unwrap();
+        let color = Color::from_str(&format!("oklab({oklab})"), None).unwrap();
+        let (l, a, b) = Oklab::from(color).into_components();
+        assert_close([l, a, b], [1.0, 0.0, 0.0], 1e-4);
+    }
+
+    #[test]
+    fn lab_of_srgb_red() {
+        let (l, a, b) = LabD50::from(Color::from_rgb([255, 0, 0])).into_components();
+        assert_close([l, a, b], [54.29, 80.81, 69.89], 0.1);
+        let (l, a, b) = LabD65::from(Color::from_rgb([255, 0, 0])).into_components();
+        assert_close([l, a, b], [53.24, 80.09, 67.2], 0.05);
+    }
+
+    #[test]
+    fn lab_d50_into_srgb() {
+        let color = Color::from_str("lab(54.2917, 80.8125, 69.8851)", None).unwrap();
+        assert_close(srgb(color), [1.0, 0.0, 0.0], 2e-3);
+        let color = Color::from_str("lab-d65(53.2408, 80.0925, 67.2032)", None).unwrap();
+        assert_close(srgb(color), [1.0, 0.0, 0.0], 2e-3);
+    }
+
+    #[test]
+    fn lch_of_white_is_achromatic() {
+        let lch = LchD50::from(Color::from_rgb([255, 255, 255]));
+        assert_close([lch.l, lch.chroma], [100.0, 0.0], 1e-2);
+    }
+
+    #[test]
+    fn functional_notation() {
+        assert_close(rgba("rgb(255, 0, 0)"), [1.0, 0.0, 0.0, 1.0], 1e-6);
+        assert_close(rgba("RGB(100% 0% 0% / 50%)"), [1.0, 0.0, 0.0, 0.5], 2e-3);
+        assert_close(rgba("rgba(255 0 0 / 0.5)"), [1.0, 0.0, 0.0, 0.5], 2e-3);
+        // the comma alpha is `0..=255` like every other channel, as the `rgba` output emits it
+        assert_close(
+            rgba("rgba(255, 0, 0, 128)"),
+            [1.0, 0.0, 0.0, 128.0 / 255.0],
+            1e-6,
+        );
+        assert_close(rgba("rgba(255, 0, 0, 25%)"), [1.0, 0.0, 0.0, 0.25], 2e-3);
+        let color = Color::from_str("rgba(255, 0, 0, 128)", None).unwrap();
+        assert_eq!(color.to_format("rgba").unwrap(), "255, 0, 0, 128");
+        assert_close(rgba("hsl(240, 1, 0.5)"), [0.0, 0.0, 1.0, 1.0], 1e-6);
+        // bare numbers are percentages in the modern syntax, `0..=1` in the comma one
+        assert_close(rgba("hsl(240 100 50)"), [0.0, 0.0, 1.0, 1.0], 1e-6);
+        assert_close(rgba("hsv(120 100 100 / 0.5)"), [0.0, 1.0, 0.0, 0.5], 1e-6);
+        assert_close(rgba("hsva(120, 1, 1, 0.5)"), [0.0, 1.0, 0.0, 0.5], 1e-6);
+        assert!(Color::from_str("hsl(240, 100, 50)", None).is_err());
+        assert_close(
+            rgba("hsl(0.5turn 100% 50% / none)"),
+            [0.0, 1.0, 1.0, 0.0],
+            1e-6,
+        );
+        assert_close(
+            rgba("oklch(62.7955% 0.257683 29.2339deg)"),
+            [1.0, 0.0, 0.0, 1.0],
+            1e-3,
+        );
+        assert_close(rgba("transparent"), [0.0; 4], 0.0);
+    }
+
+    #[test]
+    fn functional_notation_errors() {
+        for src in [
+            "rgb(255, 0 0)",
+            "rgb(300, 0, 0)",
+            "hsl(10% 0.5 0.5)",
+            "rgb(1 2)",
+            "argb(1 2 3 / 4)",
+            "foo(1 2 3)",
+        ] {
+            assert!(Color::from_str(src, None).is_err(), "{src}");
+        }
+    }
+
+    #[test]
+    fn color_function() {
+        let xyz = Xyz::<D65>::from(Color::from_str("color(srgb 1 0 0)", None).unwrap());
+        let (x, y, z) = xyz.into_components();
+        assert_close([x, y, z], [0.412_456, 0.212_673, 0.019_334], 1e-4);
+        assert_close(
+            rgba("color(display-p3 1 1 1 / 50%)"),
+            [1.0, 1.0, 1.0, 0.5],
+            1e-4,
+        );
+        assert_close(
+            rgba("color(xyz-d50 0.9642 1 0.8249)"),
+            [1.0, 1.0, 1.0, 1.0],
+            1e-3,
+        );
+        assert_close(
+            rgba("color(prophoto-rgb 1 1 1)"),
+            [1.0, 1.0, 1.0, 1.0],
+            1e-3,
+        );
+        assert!(Color::from_str("color(display-p3, 1, 0, 0)", None).is_err());
+        assert!(Color::from_str("color(unknown 1 0 0)", None).is_err());
+        // only alpha is range checked in `color()`
+        assert_close(rgba("color(srgb 1.5 -0.5 0)"), [1.5, -0.5, 0.0, 1.0], 1e-6);
+        assert!(Color::from_str("color(srgb 1 0 0 / 2)", None).is_err());
+    }
+
+    #[test]
+    fn gamut_mapping() {
+        let p3_red = Color::from_str("color(display-p3 1 0 0)", None).unwrap();
+        assert_close(
+            srgb(p3_red.clone().map_gamut::<Srgb>(GamutMapping::Clip)),
+            [1.0, 0.0, 0.0],
+            1e-6,
+        );
+        let [r, g, b] = srgb(p3_red.clone().map_gamut::<Srgb>(GamutMapping::Css));
+        assert!(
+            (r - 1.0).abs() < 1e-4 && g > 0.01 && b > 0.01,
+            "{:?}",
+            [r, g, b]
+        );
+        // the own gamut of a variant leaves it untouched
+        let own = p3_red.map_gamut::<DisplayP3>(GamutMapping::Css);
+        assert!(
+            matches!(own, Color::DisplayP3a(c) if c.red == 1.0 && c.green == 0.0 && c.blue == 0.0)
+        );
+    }
+
+    #[test]
+    fn alpha_first_notation() {
+        assert_close(rgba("argb(255, 255, 0, 0)"), [1.0, 0.0, 0.0, 1.0], 1e-6);
+        assert_close(rgba("argb(0, 0, 0, 255)"), [0.0, 0.0, 1.0, 0.0], 1e-6);
+        assert_close(rgba("fargb(0.5, 0, 1, 0)"), [0.0, 1.0, 0.0, 0.5], 1e-6);
+        assert_close(rgba("ahsl(0.5, 240, 1, 0.5)"), [0.0, 0.0, 1.0, 0.5], 1e-6);
+        assert_close(rgba("ahsv(0.25, 120, 1, 1)"), [0.0, 1.0, 0.0, 0.25], 1e-6);
+    }
+
+    #[test]
+    fn hwb_notation() {
+        assert_close(rgba("hwb(120 0 0)"), [0.0, 1.0, 0.0, 1.0], 1e-6);
+        assert_close(rgba("hwb(0 20% 20% / 50%)"), [0.8, 0.2, 0.2, 0.5], 1e-6);
+        assert_close(rgba("ahwb(0.5, 240, 0, 0.5)"), [0.0, 0.0, 0.5, 0.5], 1e-6);
+        // whiteness and blackness past 100% together are a gray
+        let gray = 0.8 / 1.4;
+        assert_close(rgba("hwb(0 80 60)"), [gray, gray, gray, 1.0], 1e-6);
+        assert_close(rgba("hwb(90 100% 100% / 0.5)"), [0.5, 0.5, 0.5, 0.5], 1e-6);
+        let (h, w, b) = Hwb::from(Color::from_rgb([51, 204, 204])).into_components();
+        assert_close([h.into_degrees(), w, b], [180.0, 0.2, 0.2], 1e-4);
+    }
+
+    #[test]
+    fn cmyk_notation() {
+        assert_close(rgba("cmyk(0, 1, 1, 0)"), [1.0, 0.0, 0.0, 1.0], 1e-6);
+        assert_close(
+            rgba("device-cmyk(0% 0% 0% 50% / 50%)"),
+            [0.5, 0.5, 0.5, 0.5],
+            1e-6,
+        );
+        let cmyk = Cmyk::from(Color::from_cmyk([0.1, 0.2, 0.3, 0.4]));
+        assert_eq!(cmyk, Cmyk::new(0.1, 0.2, 0.3, 0.4));
+    }
+
+    #[test]
+    fn xyz_and_xyy_notation() {
+        assert_close(rgba("xyz(0.9505, 1, 1.089)"), [1.0, 1.0, 1.0, 1.0], 1e-3);
+        assert_close(
+            rgba("xyz-d50(0.9642 1 0.8249 / 0.5)"),
+            [1.0, 1.0, 1.0, 0.5],
+            1e-3,
+        );
+        assert_close(rgba("xyy(0.3127, 0.329, 1)"), [1.0, 1.0, 1.0, 1.0], 1e-3);
+        let (x, y, luma) = Yxy::from(Color::from_rgb([255, 0, 0])).into_components();
+        assert_close([x, y, luma], [0.64, 0.33, 0.2126], 1e-4);
+    }
+
+    #[test]
+    fn hsluv_and_hpluv_notation() {
+        assert_close(rgba("hsluv(12.177 100 53.237)"), [1.0, 0.0, 0.0, 1.0], 1e-3);
+        assert_close(
+            rgba("hpluv(0 0% 50% / 0.5)"),
+            [0.466_35, 0.466_35, 0.466_35, 0.5],
+            1e-4,
+        );
+        let hsluv = Hsluv::from(Color::from_rgb([0, 0, 255]));
+        assert_close([hsluv.saturation, hsluv.l], [100.0, 32.3], 0.01);
+        let hpluv = Hpluv::from(Color::from_rgb([128, 128, 128]));
+        assert_close([hpluv.saturation], [0.0], 1e-3);
+    }
+
+    #[test]
+    fn hex_formats() {
+        let color = Color::from_rgba([255, 136, 0, 136]);
+        assert_eq!(
+            color.clone().to_format("hexa-lower-short").unwrap(),
+            "#f808"
+        );
+        assert_eq!(color.to_format("ahex-nohash").unwrap(), "88FF8800");
+        assert!(Color::from_rgb([0, 0, 0]).to_format("hex-unknown").is_err());
+    }
+
+    #[test]
+    fn packed_formats() {
+        assert_close(rgba("0x80FF0000"), [1.0, 0.0, 0.0, 128.0 / 255.0], 1e-6);
+        assert_close(rgba("bgr-int(255)"), [1.0, 0.0, 0.0, 1.0], 1e-6);
+        let color = Color::from_rgba([255, 136, 0, 128]);
+        assert_eq!(color.clone().to_format("argb-int").unwrap(), "2164230144");
+        assert_eq!(color.to_format("rgb-int").unwrap(), "16746496");
+    }
+
+    #[test]
+    fn ansi_formats() {
+        let color = Color::from_str("color(display-p3 0 1 0)", None).unwrap();
+        let clip = FormatOptions {
+            mapping: GamutMapping::Clip,
+            ..Default::default()
+        };
+        assert_eq!(
+            color.clone().to_format_with("ansi-fg", &clip).unwrap(),
+            "\x1b[38;2;0;255;0m"
+        );
+        assert_eq!(color.to_format("ansi-bg-16").unwrap(), "\x1b[102m");
+    }
+
+    #[test]
+    fn x11_rgb_spec() {
+        assert_close(rgba("rgb:f/8/0"), [1.0, 8.0 / 15.0, 0.0, 1.0], 1e-6);
+        assert_close(
+            rgba("rgb:ffff/00/800"),
+            [1.0, 0.0, 2048.0 / 4095.0, 1.0],
+            1e-6,
+        );
+        for src in ["rgb:ff/00", "rgb:ff/zz/00", "rgb:12345/0/0", "rgb:ff//00"] {
+            assert!(Color::from_str(src, None).is_err(), "{src}");
+        }
+
+        let color = Color::from_rgb([255, 136, 0]);
+        assert_eq!(color.clone().to_format("x11").unwrap(), "rgb:ff/88/00");
+        assert_eq!(color.to_format("x11-16").unwrap(), "rgb:ffff/8888/0000");
+    }
+
+    #[test]
+    fn own_format_is_lossless() {
+        let oklch = Oklch::from(Color::from_oklch([0.7, 0.4, 30.0]));
+        assert_eq!(
+            [oklch.l, oklch.chroma, oklch.hue.into_inner()],
+            [0.7, 0.4, 30.0]
+        );
+
+        let color = Color::from_str("color(display-p3 1.2 0.5 -0.3)", None).unwrap();
+        assert_eq!(DisplayP3::from(color).into_components(), (1.2, 0.5, -0.3));
+
+        let color = Color::from_str("rgb(127.5 0 0 / 25%)", None).unwrap();
+        assert_eq!(color.to_format("frgba").unwrap(), "0.5, 0, 0, 0.25");
+
+        // only `From` keeps the own variant out of gamut, formats still map it
+        let color = Color::from_str("color(srgb 1.2 0.5 -0.3)", None).unwrap();
+        assert_eq!(
+            Srgb::from(color.clone()).into_components(),
+            (1.2, 0.5, -0.3)
+        );
+        assert_ne!(
+            color.clone().to_format("css-rgb").unwrap(),
+            "rgb(306 128 -76)"
+        );
+        let clip = FormatOptions {
+            mapping: GamutMapping::Clip,
+            ..Default::default()
+        };
+        assert_eq!(
+            color.clone().to_format_with("css-rgb", &clip).unwrap(),
+            "rgb(255 128 0)"
+        );
+        assert_ne!(
+            color.clone().to_format("hex").unwrap(),
+            color.to_format_with("hex", &clip).unwrap()
+        );
+
+        let color = Color::from_str("color(srgb-linear 1.2 0.5 -0.3)", None).unwrap();
+        let glsl = r#"glsl-vec3(sep=",")"#;
+        assert_ne!(color.clone().to_format(glsl).unwrap(), "vec3(1.2,0.5,-0.3)");
+        assert_eq!(
+            color.to_format_with(glsl, &clip).unwrap(),
+            "vec3(1.0,0.5,0.0)"
+        );
+    }
+
+    #[test]
+    fn format_params() {
+        let color = Color::from_rgba([255, 0, 0, 255]);
+        assert_eq!(
+            color
+                .clone()
+                .to_format(r#"hsl(precision=1, sep=" ")"#)
+                .unwrap(),
+            "0.0 1.0 0.5"
+        );
+        assert_eq!(
+            color.clone().to_format("hex(lower, nohash)").unwrap(),
+            "ff0000"
+        );
+        assert_eq!(
+            color.clone().to_format("ahex(0x, lower)").unwrap(),
+            "0xffff0000"
+        );
+        assert!(color.clone().to_format("hexa(0x)").is_err());
+        assert_eq!(
+            color
+                .clone()
+                .to_format(r#"rgb(prefix="rgb(", suffix=")")"#)
+                .unwrap(),
+            "rgb(255, 0, 0)"
+        );
+        assert_eq!(color.clone().to_format("ahsl").unwrap(), "1, 0, 1, 0.5");
+        assert!(color.clone().to_format("rgb(nohash)").is_err());
+        assert!(color.to_format("hex(wide)").is_err());
+    }
+
+    #[test]
+    fn user_defined_formats() {
+        let formats = Formats::from([("css".into(), "rgb({r} {g} {b} / {a})".into())]);
+        let options = FormatOptions {
+            formats: Some(&formats),
+            ..Default::default()
+        };
+
+        let color = Color::from_rgba([255, 0, 0, 255]);
+        assert_eq!(
+            color.clone().to_format_with("css", &options).unwrap(),
+            "rgb(255 0 0 / 1)"
+        );
+        assert_eq!(
+            color
+                .clone()
+                .to_format_with("css(prefix=\"c:\")", &options)
+                .unwrap(),
+            "c:rgb(255 0 0 / 1)"
+        );
+        assert!(color.clone().to_format_with("scss", &options).is_err());
+        assert!(color.to_format("css").is_err());
+    }
+
+    #[test]
+    fn css_formats() {
+        let color = Color::from_str("color(display-p3 0 1 0)", None).unwrap();
+        assert_eq!(
+            color.clone().to_format("css-rgb(precision=1)").unwrap(),
+            "rgb(0.0 251.4 40.7)"
+        );
+        assert_eq!(
+            color.to_format("css-oklch").unwrap(),
+            "oklch(84.88% 0.3685 145.64deg)"
+        );
+    }
+
+    #[test]
+    fn name_format() {
+        let color = Color::from_rgba([254, 1, 0, 128]);
+        assert_eq!(color.clone().to_format("name").unwrap(), "red");
+        assert_eq!(color.clone().to_format("name(upper)").unwrap(), "RED");
+        assert!(
+            matches!(color.clone().to_format("name(exact)"), Err(Error::NoNamedColor(hex)) if hex == "#FE0100")
+        );
+        assert!(color.to_format("name(nearest)").is_err());
+    }
+
+    #[test]
+    fn name_databases() {
+        let x11 = [NameDatabase::X11];
+        let color = Color::from_str_with("Slate Blue 3", None, &x11).unwrap();
+        assert_close(
+            rgba("#6959CD"),
+            Srgba::from(color.clone()).into_components().into(),
+            1e-6,
+        );
+        assert!(Color::from_str("slateblue3", None).is_err());
+
+        let options = FormatOptions {
+            names: Some(&x11),
+            ..Default::default()
+        };
+        assert_eq!(
+            color.to_format_with("name", &options).unwrap(),
+            "slateblue3"
+        );
+    }
+
+    #[test]
+    fn kelvin_format() {
+        for kelvin in [2700, 4000, 6500] {
+            let color = Color::from_str(&format!("{kelvin}K"), None).unwrap();
+            let cct: f32 = color
+                .to_format("kelvin")
+                .unwrap()
+                .trim_end_matches('K')
+                .parse()
+                .unwrap();
+            assert!((cct - kelvin as f32).abs() < 50.0, "{kelvin}: {cct}");
+        }
+
+        assert_eq!(
+            Color::from_rgb([255, 255, 255])
+                .to_format("kelvin")
+                .unwrap(),
+            "6503K"
+        );
+    }
+
+    #[test]
+    fn shader_formats() {
+        let color = Color::from_str("color(srgb-linear 0.5 0.25 1)", None).unwrap();
+        assert_eq!(
+            color.clone().to_format(r#"glsl-vec3(sep=",")"#).unwrap(),
+            "vec3(0.5,0.25,1.0)"
+        );
+        assert_eq!(
+            color.to_format("frgb(precision=3)").unwrap(),
+            "0.735, 0.537, 1.000"
+        );
+    }
+
+    #[test]
+    fn literal_formats() {
+        let color = Color::from_rgba([255, 136, 0, 128]);
+        let format = |format: &str| color.clone().to_format(format).unwrap();
+
+        assert_eq!(
+            format("swift-uicolor"),
+            "UIColor(red: 1.000, green: 0.533, blue: 0.000, alpha: 0.502)"
+        );
+        assert_eq!(
+            format("swift-color"),
+            "Color(.sRGB, red: 1.000, green: 0.533, blue: 0.000, opacity: 0.502)"
+        );
+        assert_eq!(format("kotlin-color"), "Color(0x80FF8800)");
+        assert_eq!(format("compose-color"), "Color(0x80FF8800)");
+        assert_eq!(format("flutter-color"), "Color(0x80FF8800)");
+        assert_eq!(format("csharp-color"), "Color.FromArgb(128, 255, 136, 0)");
+        assert_eq!(format("rust-rgba"), "Rgba(255, 136, 0, 128)");
+        assert_eq!(format("rust-rgb"), "Rgb(255, 136, 0)");
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Theme {
+            accent: Color,
+        }
+
+        let theme = Theme {
+            accent: Color::from_rgba([255, 136, 0, 128]),
+        };
+        let src = toml::to_string(&theme).unwrap();
+        assert_eq!(src, "accent = \"#FF880080\"\n");
+        let theme: Theme = toml::from_str(&src).unwrap();
+        assert_close(
+            rgba("#FF880080"),
+            Srgba::from(theme.accent).into_components().into(),
+            1e-6,
+        );
+
+        let theme: Theme = toml::from_str("accent = \"oklch(62.8% 0.2577 29.23)\"").unwrap();
+        assert_eq!(theme.accent.to_format("hex").unwrap(), "#FF0000");
+        assert!(toml::from_str::<Theme>("accent = \"not a color\"").is_err());
+
+        #[derive(serde::Serialize)]
+        struct HexTheme {
+            #[serde(with = "serde_with::hex")]
+            accent: Color,
+        }
+
+        let theme = HexTheme {
+            accent: Color::from_str("oklch(0.627955 0.257683 29.2339)", None).unwrap(),
+        };
+        assert_eq!(toml::to_string(&theme).unwrap(), "accent = \"#FF0000\"\n");
+    }
+
+    #[test]
+    fn equality_and_hashing() {
+        use std::collections::HashSet;
+
+        let red: Color = "red".parse().unwrap();
+        assert_eq!(red, Color::from_str("hsl(0 100% 50%)", None).unwrap());
+        assert_eq!(red, Color::from(Srgb::new(255u8, 0, 0)));
+        assert_ne!(red, Color::from_rgba([255, 0, 0, 128]));
+        assert!(red.approx_eq(&Color::from_rgb([254, 1, 0]), 0.02));
+        assert!(!red.approx_eq(&Color::from_rgb([0, 0, 255]), 0.02));
+
+        let set: HashSet<Color> = ["red", "#F00", "rgb(100% 0 0)", "blue"]
+            .map(|src| src.parse().unwrap())
+            .into();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn display_and_from() {
+        assert_eq!(Color::from_rgb([255, 136, 0]).to_string(), "#FF8800");
+        assert_eq!(
+            Color::from_rgba([255, 136, 0, 128]).to_string(),
+            "#FF880080"
+        );
+
+        let color = Color::from(Oklch::new(0.627_955, 0.257_683, 29.2339));
+        assert!(matches!(color, Color::Oklcha(_)));
+        assert_eq!(color.to_string(), "oklch(0.627955 0.257683 29.2339)");
+        assert_eq!(
+            Color::from(Srgba::new(0.0, 0.0, 1.0, 0.5)).to_string(),
+            "color(srgb 0 0 1 / 0.5)"
+        );
+
+        let cmyk = Color::from(Cmyk::new(0.0, 0.5, 1.0, 0.0));
+        assert_eq!(cmyk.to_string(), "cmyk(0 0.5 1 0)");
+        let hpluv = Color::from(Hpluv::new(12.0, 50.0, 60.0));
+        assert_eq!(hpluv.to_string(), "hpluv(12 50 60)");
+    }
+
+    #[test]
+    fn lossless_round_trip() {
+        for src in [
+            "#FF8800",
+            "#FF880080",
+            "rgb(127.5 0 0)",
+            "hsl(120 25 50 / 0.3)",
+            "hsv(300, 0.5, 0.25)",
+            "hwb(200 10 20)",
+            "cmyk(0.1 0.2 0.3 0.4)",
+            "oklch(0.7 0.1 20)",
+            "lab(54.29 80.81 69.89)",
+            "lch-d65(50 30 270)",
+            "color(display-p3 1.2 0.5 -0.3 / 0.5)",
+            "color(xyz-d50 0.1 0.2 0.3)",
+            "xyy(0.3127 0.329 0.5)",
+        ] {
+            let color = Color::from_str(src, None).unwrap();
+            let lossless = color.to_lossless();
+            let parsed = Color::from_str(&lossless, None).unwrap();
+
+            assert_eq!(
+                std::mem::discriminant(&parsed),
+                std::mem::discriminant(&color),
+                "{src}: {lossless}"
+            );
+            assert_eq!(parsed.to_lossless(), lossless, "{src}");
+        }
+
+        assert_eq!(
+            Color::from_str("rgb(127.5 0 0)", None)
+                .unwrap()
+                .to_lossless(),
+            "color(srgb 0.5 0 0)"
+        );
+        assert_eq!(
+            Color::from_str("hsl(120 25 50 / 0.3)", None)
+                .unwrap()
+                .to_lossless(),
+            "hsla(120, 0.25, 0.5, 0.3)"
+        );
+        assert_eq!(
+            Color::from_str("#F80", None)
+                .unwrap()
+                .to_format("lossless")
+                .unwrap(),
+            "#FF8800"
+        );
+    }
+}
@@ -1,3 +1,7 @@
+use crate::format::FormatOptions;
+use crate::gamut::GamutMapping;
+use crate::names::NameDatabase;
+use crate::template::Template;
 use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -166,6 +170,7 @@ pub const DEFAULT_COLORS: [(Cow<'static, str>, Cow<'static, str>); 148] = [
 pub type Palette<'a> = HashMap<Cow<'a, str>, Cow<'a, str>>;
 pub type Palettes<'a> = HashMap<Cow<'a, str>, Palette<'a>>;
 pub type PalettesBase<'a> = HashMap<Cow<'a, str>, PaletteBase<'a>>;
+pub type Formats<'a> = HashMap<Cow<'a, str>, Cow<'a, str>>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaletteBase<'a> {
@@ -196,6 +201,12 @@ pub struct ConfigBase<'a> {
     pub suffix: Cow<'a, str>,
     pub palette: Cow<'a, str>,
     pub autoload: AutoLoad,
+    #[serde(default)]
+    pub gamut: GamutMapping,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub formats: Formats<'a>,
+    #[serde(default = "default_names")]
+    pub names: Vec<Cow<'a, str>>,
     pub palettes: HashMap<Cow<'a, str>, PaletteOrFile<'a>>,
 }
 
@@ -204,9 +215,22 @@ pub struct Config<'a> {
     pub prefix: Cow<'a, str>,
     pub suffix: Cow<'a, str>,
     pub palette: Cow<'a, str>,
+    pub gamut: GamutMapping,
+    pub formats: Formats<'a>,
+    pub names: Vec<NameDatabase>,
     pub palettes: Palettes<'a>,
 }
 
+impl<'a> Config<'a> {
+    pub fn format_options(&self) -> FormatOptions<'_> {
+        FormatOptions {
+            mapping: self.gamut,
+            formats: Some(&self.formats),
+            names: Some(&self.names),
+        }
+    }
+}
+
 impl AutoLoad {
     pub fn parse<'a>(self) -> Result<PalettesBase<'a>> {
         match self {
@@ -292,9 +316,16 @@ impl<'a> ConfigBase<'a> {
             suffix,
             palette,
             autoload,
+            gamut,
+            formats,
+            names,
             palettes,
         } = self;
 
+        for template in formats.values() {
+            Template::parse(template)?;
+        }
+
         let autoload = autoload.parse()?;
 
         let mut palettes_base = palettes
@@ -307,6 +338,21 @@ impl<'a> ConfigBase<'a> {
             palettes_base.entry(k).or_insert(v);
         }
 
+        let names = names
+            .iter()
+            .map(|name| NameDatabase::load(name))
+            .collect::<Result<Vec<_>>>()?;
+
+        // every database can also be inherited from like an autoloaded palette
+        for database in &names {
+            palettes_base
+                .entry(database.name().to_owned().into())
+                .or_insert_with(|| PaletteBase {
+                    inherits: Vec::new(),
+                    colors: database.palette(),
+                });
+        }
+
         let palettes = palettes_base
             .clone()
             .into_iter()
@@ -317,6 +363,9 @@ impl<'a> ConfigBase<'a> {
             prefix,
             suffix,
             palette,
+            gamut,
+            formats,
+            names,
             palettes,
         })
     }
@@ -331,6 +380,10 @@ impl<'a> PaletteOrFile<'a> {
     }
 }
 
+fn default_names<'a>() -> Vec<Cow<'a, str>> {
+    vec![Cow::Borrowed("css")]
+}
+
 pub fn load_config<'de, T: Deserialize<'de>>(name: impl AsRef<Path>) -> Result<T> {
     let dir = get_config_dir()?;
     let path = dir.join(name);
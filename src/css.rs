@@ -0,0 +1,169 @@
+//! Modern CSS color functions, `css-rgb`, `css-hsl`, `css-hwb`, `css-lab` and `css-oklch`
+//!
+//! The output is valid CSS as is, `rgb(255 0 0 / 0.5)` or `hsl(210deg 50% 40%)`,
+//! alpha is only emitted when the color isn't opaque. `rgb`, `hsl` and `hwb` are
+//! gamut mapped into sRGB, `lab` (D50 like CSS) and `oklch` are unbounded.
+//!
+//! Without a `precision` parameter trailing zeros are dropped
+
+use crate::color::{Color, LabaD50};
+use crate::format::FormatParams;
+use crate::gamut::{GamutMapping, TargetGamut};
+use palette::{Hsla, Hwba, Oklcha, Srgba};
+
+/// Oklch chroma below which a color is treated as a gray
+const ACHROMATIC: f32 = 1e-4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Css {
+    Rgb,
+    Hsl,
+    Hwb,
+    Lab,
+    Oklch,
+}
+
+impl Css {
+    pub fn from_name(name: &str) -> Option<Self> {
+        let css = match name {
+            "css-rgb" => Self::Rgb,
+            "css-hsl" => Self::Hsl,
+            "css-hwb" => Self::Hwb,
+            "css-lab" => Self::Lab,
+            "css-oklch" => Self::Oklch,
+            _ => return None,
+        };
+
+        Some(css)
+    }
+
+    pub fn format(self, color: Color, params: &FormatParams, mapping: GamutMapping) -> String {
+        let number = |value: f32, decimals: usize| number(params, value, decimals);
+
+        let (function, components, alpha) = match self {
+            Self::Rgb => {
+                let rgb = Srgba::from(<Srgba as TargetGamut>::map_gamut(color, mapping));
+                let [r, g, b] = [rgb.red, rgb.green, rgb.blue].map(|c| number(c * 255.0, 0));
+
+                ("rgb", format!("{r} {g} {b}"), rgb.alpha)
+            }
+            Self::Hsl => {
+                let hsl = Hsla::from(<Hsla as TargetGamut>::map_gamut(color, mapping));
+                let h = number(hsl.hue.into_positive_degrees(), 2);
+                let s = number(hsl.saturation * 100.0, 2);
+                let l = number(hsl.lightness * 100.0, 2);
+
+                ("hsl", format!("{h}deg {s}% {l}%"), hsl.alpha)
+            }
+            Self::Hwb => {
+                let hwb = Hwba::from(<Hwba as TargetGamut>::map_gamut(color, mapping));
+                let h = number(hwb.hue.into_positive_degrees(), 2);
+                let w = number(hwb.whiteness * 100.0, 2);
+                let b = number(hwb.blackness * 100.0, 2);
+
+                ("hwb", format!("{h}deg {w}% {b}%"), hwb.alpha)
+            }
+            Self::Lab => {
+                let lab = LabaD50::from(color);
+                let [l, a, b] = [lab.l, lab.a, lab.b].map(|c| number(c, 2));
+
+                ("lab", format!("{l}% {a} {b}"), lab.alpha)
+            }
+            Self::Oklch => {
+                let oklch = Oklcha::from(color);
+                let l = number(oklch.l * 100.0, 2);
+                let c = number(oklch.chroma, 4);
+                // the hue of a gray is noise from the conversion
+                let h = match oklch.chroma < ACHROMATIC {
+                    true => "0".to_owned(),
+                    false => number(oklch.hue.into_positive_degrees(), 2),
+                };
+
+                ("oklch", format!("{l}% {c} {h}deg"), oklch.alpha)
+            }
+        };
+
+        if alpha < 1.0 {
+            format!("{function}({components} / {})", number(alpha, 3))
+        } else {
+            format!("{function}({components})")
+        }
+    }
+}
+
+/// `value` with the precision of the params, or `decimals` without trailing zeros
+fn number(params: &FormatParams, value: f32, decimals: usize) -> String {
+    if params.precision.is_some() {
+        return params.number(value);
+    }
+
+    let value = format!("{value:.decimals$}");
+    let value = match value.contains('.') {
+        true => value.trim_end_matches('0').trim_end_matches('.'),
+        false => &value,
+    };
+
+    // `-0` from rounding a tiny negative value
+    match value {
+        "-0" => "0".to_owned(),
+        value => value.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(name: &str, color: Color) -> String {
+        Css::from_name(name).unwrap().format(
+            color,
+            &FormatParams::default(),
+            GamutMapping::default(),
+        )
+    }
+
+    #[test]
+    fn from_name() {
+        assert_eq!(Css::from_name("css-oklch"), Some(Css::Oklch));
+        assert_eq!(Css::from_name("css-lch"), None);
+        assert_eq!(Css::from_name("rgb"), None);
+    }
+
+    #[test]
+    fn functions() {
+        let color = Color::from_rgb([0x33, 0x66, 0x99]);
+        assert_eq!(format("css-rgb", color.clone()), "rgb(51 102 153)");
+        assert_eq!(format("css-hsl", color.clone()), "hsl(210deg 50% 40%)");
+        assert_eq!(format("css-hwb", color), "hwb(210deg 20% 40%)");
+
+        let red = Color::from_rgb([255, 0, 0]);
+        assert_eq!(format("css-lab", red.clone()), "lab(54.29% 80.81 69.89)");
+        assert_eq!(format("css-oklch", red), "oklch(62.8% 0.2577 29.23deg)");
+    }
+
+    #[test]
+    fn alpha_and_grays() {
+        let color = Color::from_frgba([1.0, 1.0, 1.0, 0.25]);
+        assert_eq!(format("css-rgb", color.clone()), "rgb(255 255 255 / 0.25)");
+        assert_eq!(
+            format("css-oklch", color.clone()),
+            "oklch(100% 0 0deg / 0.25)"
+        );
+        assert_eq!(format("css-lab", color), "lab(100% 0 0 / 0.25)");
+    }
+
+    #[test]
+    fn trailing_zeros() {
+        let params = FormatParams::default();
+        assert_eq!(number(&params, 1.5, 2), "1.5");
+        assert_eq!(number(&params, 2.0, 2), "2");
+        assert_eq!(number(&params, 100.0, 0), "100");
+        assert_eq!(number(&params, -0.001, 2), "0");
+
+        let params = FormatParams {
+            precision: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(number(&params, 2.0, 0), "2.000");
+    }
+}
@@ -0,0 +1,175 @@
+//! RGB standards from CSS Color Level 4 that palette doesn't ship,
+//! used by `color(display-p3 ...)` and friends
+
+use palette::Yxy;
+use palette::encoding::{FromLinear, IntoLinear};
+use palette::num::Real;
+use palette::rgb::{Primaries, Rgb, RgbSpace, RgbStandard, Rgba};
+use palette::white_point::{Any, D50, D65};
+
+pub type DisplayP3 = Rgb<DisplayP3Standard>;
+pub type DisplayP3a = Rgba<DisplayP3Standard>;
+pub type Rec2020 = Rgb<Rec2020Standard>;
+pub type Rec2020a = Rgba<Rec2020Standard>;
+pub type A98Rgb = Rgb<A98RgbStandard>;
+pub type A98Rgba = Rgba<A98RgbStandard>;
+pub type ProPhotoRgb = Rgb<ProPhotoRgbStandard>;
+pub type ProPhotoRgba = Rgba<ProPhotoRgbStandard>;
+
+macro_rules! impl_standard {
+    ($name:ident, $wp:ty, $tf:ty, [$($xr:literal, $yr:literal, $lr:literal),+]) => {
+        impl<T: Real> Primaries<T> for $name {
+            impl_standard!(@primaries T, [red, green, blue], [$($xr, $yr, $lr),+]);
+        }
+
+        impl RgbSpace for $name {
+            type Primaries = $name;
+            type WhitePoint = $wp;
+        }
+
+        impl RgbStandard for $name {
+            type Space = $name;
+            type TransferFn = $tf;
+        }
+    };
+    (@primaries $t:ident, [$($fname:ident),+], [$($x:literal, $y:literal, $l:literal),+]) => {
+        $(fn $fname() -> Yxy<Any, $t> {
+            Yxy::new($t::from_f64($x), $t::from_f64($y), $t::from_f64($l))
+        })+
+    };
+}
+
+macro_rules! impl_transfer_fn {
+    ($name:ident, |$x:ident| into: $into:expr, from: $from:expr) => {
+        impl IntoLinear<f32, f32> for $name {
+            fn into_linear(x: f32) -> f32 {
+                // sign is preserved so out of gamut values survive a round trip
+                let $x = x.abs();
+                $into.copysign(x)
+            }
+        }
+
+        impl FromLinear<f32, f32> for $name {
+            fn from_linear(x: f32) -> f32 {
+                let $x = x.abs();
+                $from.copysign(x)
+            }
+        }
+    };
+}
+
+/// Display P3, sRGB transfer function with DCI-P3 primaries
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DisplayP3Standard;
+
+/// ITU-R BT.2020
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec2020Standard;
+
+/// Adobe RGB (1998)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct A98RgbStandard;
+
+/// ProPhoto RGB (ROMM RGB), uses D50 as white point
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ProPhotoRgbStandard;
+
+#[rustfmt::skip]
+impl_standard!(DisplayP3Standard, D65, palette::encoding::Srgb, [
+    0.680, 0.320, 0.228975,
+    0.265, 0.690, 0.691739,
+    0.150, 0.060, 0.079287
+]);
+
+#[rustfmt::skip]
+impl_standard!(Rec2020Standard, D65, Rec2020Standard, [
+    0.708, 0.292, 0.262700,
+    0.170, 0.797, 0.677998,
+    0.131, 0.046, 0.059302
+]);
+
+#[rustfmt::skip]
+impl_standard!(A98RgbStandard, D65, A98RgbStandard, [
+    0.6400, 0.3300, 0.297345,
+    0.2100, 0.7100, 0.627364,
+    0.1500, 0.0600, 0.075291
+]);
+
+#[rustfmt::skip]
+impl_standard!(ProPhotoRgbStandard, D50, ProPhotoRgbStandard, [
+    0.734699, 0.265301, 0.288075,
+    0.159597, 0.840403, 0.711835,
+    0.036598, 0.000105, 0.000090
+]);
+
+const REC2020_ALPHA: f32 = 1.099_296_8;
+const REC2020_BETA: f32 = 0.018_053_97;
+
+impl_transfer_fn!(Rec2020Standard, |x|
+    into: if x < REC2020_BETA * 4.5 {
+        x / 4.5
+    } else {
+        ((x + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45)
+    },
+    from: if x > REC2020_BETA {
+        REC2020_ALPHA * x.powf(0.45) - (REC2020_ALPHA - 1.0)
+    } else {
+        x * 4.5
+    }
+);
+
+impl_transfer_fn!(A98RgbStandard, |x|
+    into: x.powf(563.0 / 256.0),
+    from: x.powf(256.0 / 563.0)
+);
+
+impl_transfer_fn!(ProPhotoRgbStandard, |x|
+    into: if x <= 16.0 / 512.0 {
+        x / 16.0
+    } else {
+        x.powf(1.8)
+    },
+    from: if x >= 1.0 / 512.0 {
+        x.powf(1.0 / 1.8)
+    } else {
+        x * 16.0
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use palette::Xyz;
+    use palette::convert::FromColorUnclamped;
+
+    fn assert_close(actual: (f32, f32, f32), expected: (f32, f32, f32)) {
+        let (a, e) = (
+            [actual.0, actual.1, actual.2],
+            [expected.0, expected.1, expected.2],
+        );
+        let close = a.iter().zip(&e).all(|(a, e)| (a - e).abs() <= 1e-3);
+        assert!(close, "{actual:?} != {expected:?}");
+    }
+
+    #[test]
+    fn red_primaries() {
+        let p3 = Xyz::<D65, f32>::from_color_unclamped(DisplayP3::new(1.0, 0.0, 0.0));
+        assert_close(p3.into_components(), (0.486_571, 0.228_975, 0.0));
+        let rec2020 = Xyz::<D65, f32>::from_color_unclamped(Rec2020::new(1.0, 0.0, 0.0));
+        assert_close(rec2020.into_components(), (0.636_958, 0.262_7, 0.0));
+        let a98 = Xyz::<D65, f32>::from_color_unclamped(A98Rgb::new(1.0, 0.0, 0.0));
+        assert_close(a98.into_components(), (0.576_669, 0.297_345, 0.027_031));
+        let prophoto = Xyz::<D50, f32>::from_color_unclamped(ProPhotoRgb::new(1.0, 0.0, 0.0));
+        assert_close(prophoto.into_components(), (0.797_761, 0.288_071, 0.0));
+    }
+
+    #[test]
+    fn transfer_functions_round_trip() {
+        for x in [-0.5, 0.0, 0.001, 0.01, 0.3, 1.0, 1.2] {
+            let rec2020 = Rec2020Standard::from_linear(Rec2020Standard::into_linear(x));
+            let a98 = A98RgbStandard::from_linear(A98RgbStandard::into_linear(x));
+            let prophoto = ProPhotoRgbStandard::from_linear(ProPhotoRgbStandard::into_linear(x));
+            assert_close((rec2020, a98, prophoto), (x, x, x));
+        }
+    }
+}
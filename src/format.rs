@@ -0,0 +1,243 @@
+//! Parameters given to an output format, `hsl(precision=1, sep=" ")` or `hex(lower, nohash)`
+//!
+//! - `precision=N` rounds every component to `N` decimals
+//! - `sep="..."` separates the components, `", "` by default
+//! - `prefix="..."` and `suffix="..."` wrap the whole output
+//! - `lower` and `upper` change the case of the output
+//!
+//! `precision` and `sep` only apply to formats that emit a list of components,
+//! any other bare word is a modifier of the format itself like `nohash` for hex
+
+use crate::config::Formats;
+use crate::gamut::GamutMapping;
+use crate::names::NameDatabase;
+use crate::{Error, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    Lower,
+    Upper,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FormatParams {
+    pub precision: Option<usize>,
+    pub sep: Option<String>,
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
+    pub case: Option<Case>,
+    /// Bare words that are left for the format to interpret
+    pub flags: Vec<String>,
+}
+
+/// Settings from the config that apply to every emitted color
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FormatOptions<'a> {
+    pub mapping: GamutMapping,
+    /// User defined formats, consulted when a format isn't built in
+    pub formats: Option<&'a Formats<'a>>,
+    /// Name databases for parsing and the `name` format, only CSS when `None`
+    pub names: Option<&'a [NameDatabase]>,
+}
+
+/// Format name with its parameters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatSpec<'a> {
+    pub name: &'a str,
+    pub params: FormatParams,
+}
+
+impl<'a> FormatSpec<'a> {
+    pub fn parse(src: &'a str) -> Result<Self> {
+        let err = || Error::FailedToParseFormat(src.to_owned());
+
+        let Some((name, args)) = src.split_once('(') else {
+            return Ok(Self {
+                name: src.trim(),
+                params: FormatParams::default(),
+            });
+        };
+
+        let args = args.trim_end().strip_suffix(')').ok_or_else(err)?;
+        let mut params = FormatParams::default();
+
+        for arg in split_args(args).ok_or_else(err)? {
+            match arg {
+                (key, Some(value)) => match key.as_str() {
+                    "precision" => params.precision = Some(value.parse().map_err(|_| err())?),
+                    "sep" => params.sep = Some(value),
+                    "prefix" => params.prefix = Some(value),
+                    "suffix" => params.suffix = Some(value),
+                    _ => return Err(err()),
+                },
+                (flag, None) => match flag.as_str() {
+                    "lower" => params.case = Some(Case::Lower),
+                    "upper" => params.case = Some(Case::Upper),
+                    _ => params.flags.push(flag),
+                },
+            }
+        }
+
+        Ok(Self {
+            name: name.trim(),
+            params,
+        })
+    }
+}
+
+impl FormatParams {
+    pub fn number(&self, value: f32) -> String {
+        match self.precision {
+            Some(precision) => format!("{value:.precision$}"),
+            None => value.to_string(),
+        }
+    }
+
+    /// Components joined by the separator
+    pub fn join(&self, values: &[f32]) -> String {
+        let sep = self.sep.as_deref().unwrap_or(", ");
+
+        values
+            .iter()
+            .map(|value| self.number(*value))
+            .collect::<Vec<_>>()
+            .join(sep)
+    }
+
+    /// Applies case, prefix and suffix to the formatted color
+    pub fn finish(&self, color: String) -> String {
+        let color = match self.case {
+            Some(Case::Lower) => color.to_lowercase(),
+            Some(Case::Upper) => color.to_uppercase(),
+            None => color,
+        };
+
+        let prefix = self.prefix.as_deref().unwrap_or_default();
+        let suffix = self.suffix.as_deref().unwrap_or_default();
+
+        format!("{prefix}{color}{suffix}")
+    }
+}
+
+/// Splits `key="value", flag` into keys with optional values,
+/// quoted values can contain commas and `\"`
+fn split_args(src: &str) -> Option<Vec<(String, Option<String>)>> {
+    let mut args = Vec::new();
+    let mut chars = src.chars().peekable();
+
+    loop {
+        let mut key = String::new();
+
+        while let Some(c) = chars.next_if(|c| *c != ',' && *c != '=') {
+            key.push(c);
+        }
+
+        let key = key.trim().to_owned();
+
+        let value = if chars.next_if_eq(&'=').is_some() {
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+            let mut value = String::new();
+
+            if chars.next_if_eq(&'"').is_some() {
+                loop {
+                    match chars.next()? {
+                        '"' => break,
+                        '\\' => value.push(chars.next()?),
+                        c => value.push(c),
+                    }
+                }
+
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            } else {
+                while let Some(c) = chars.next_if(|c| *c != ',') {
+                    value.push(c);
+                }
+
+                value = value.trim().to_owned();
+            }
+
+            Some(value)
+        } else {
+            None
+        };
+
+        match (key.is_empty(), &value) {
+            // `hex()` has no arguments at all
+            (true, None) if args.is_empty() && chars.peek().is_none() => break,
+            (true, _) => return None,
+            _ => args.push((key, value)),
+        }
+
+        match chars.next() {
+            Some(',') => {}
+            None => break,
+            Some(_) => return None,
+        }
+    }
+
+    Some(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(src: &str) -> Vec<(String, Option<String>)> {
+        split_args(src).unwrap()
+    }
+
+    fn arg(key: &str, value: Option<&str>) -> (String, Option<String>) {
+        (key.to_owned(), value.map(str::to_owned))
+    }
+
+    #[test]
+    fn split_flags_and_values() {
+        assert_eq!(args(""), []);
+        assert_eq!(
+            args(" lower , nohash"),
+            [arg("lower", None), arg("nohash", None)]
+        );
+        assert_eq!(
+            args(r#"sep=", ", precision = 2"#),
+            [arg("sep", Some(", ")), arg("precision", Some("2"))]
+        );
+        assert_eq!(args(r#"prefix="\"\\""#), [arg("prefix", Some(r#""\"#))]);
+    }
+
+    #[test]
+    fn split_errors() {
+        for src in [",", "lower,", "=1", r#"sep="unterminated"#, r#"sep="a" b"#] {
+            assert_eq!(split_args(src), None, "{src}");
+        }
+    }
+
+    #[test]
+    fn parse_spec() {
+        let spec = FormatSpec::parse(r#"hsl(precision=1, sep=" ", upper, short)"#).unwrap();
+        assert_eq!(spec.name, "hsl");
+        assert_eq!(spec.params.precision, Some(1));
+        assert_eq!(spec.params.sep.as_deref(), Some(" "));
+        assert_eq!(spec.params.case, Some(Case::Upper));
+        assert_eq!(spec.params.flags, ["short"]);
+
+        assert_eq!(FormatSpec::parse(" hex ").unwrap().name, "hex");
+        assert_eq!(
+            FormatSpec::parse("hex()").unwrap().params,
+            FormatParams::default()
+        );
+        assert!(FormatSpec::parse("hsl(precision=x)").is_err());
+        assert!(FormatSpec::parse("hsl(width=2)").is_err());
+        assert!(FormatSpec::parse("hsl(precision=1").is_err());
+    }
+
+    #[test]
+    fn join_and_finish() {
+        let params = FormatSpec::parse(r#"x(precision=2, sep="/", prefix="<", suffix=">", upper)"#)
+            .unwrap()
+            .params;
+        assert_eq!(params.join(&[1.0, 0.125, -3.0]), "1.00/0.12/-3.00");
+        assert_eq!(params.finish("abc".to_owned()), "<ABC>");
+        assert_eq!(FormatParams::default().join(&[0.5, 1.0]), "0.5, 1");
+    }
+}
@@ -0,0 +1,208 @@
+//! Bringing colors into the gamut of the format they are emitted in,
+//! see <https://www.w3.org/TR/css-color-4/#gamut-mapping>
+
+use crate::color::{Color, XyzD50};
+use crate::encoding::{A98Rgb, DisplayP3, ProPhotoRgb, Rec2020};
+use palette::chromatic_adaptation::AdaptFrom;
+use palette::convert::{FromColorUnclamped, IntoColorUnclamped};
+use palette::{Clamp, Oklab, Oklch, Srgb, WithAlpha, Xyz};
+use serde::{Deserialize, Serialize};
+
+/// Just noticeable difference in ΔEOK
+const JND: f32 = 0.02;
+const EPSILON: f32 = 0.0001;
+/// Channels this far outside of `0..=1` still count as in gamut
+const GAMUT_EPSILON: f32 = 0.000075;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GamutMapping {
+    /// Reduce chroma in Oklch until clipping is no longer noticeable
+    #[default]
+    Css,
+    /// Clamp every channel of the target space
+    Clip,
+}
+
+/// A bounded RGB space colors can be mapped into
+pub trait Gamut: Copy + Clamp {
+    fn from_oklab(color: Oklab) -> Self;
+    fn into_oklab(self) -> Oklab;
+    fn channels(&self) -> [f32; 3];
+    fn into_color_with_alpha(self, alpha: f32) -> Color;
+
+    fn is_in_gamut(&self) -> bool {
+        self.channels()
+            .iter()
+            .all(|c| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(c))
+    }
+}
+
+/// Gamut a format is emitted in, if it has one
+pub trait TargetGamut {
+    fn map_gamut(color: Color, mapping: GamutMapping) -> Color;
+}
+
+macro_rules! impl_gamut {
+    ($($rgb:ident => $variant:ident),+ $(,)?) => {
+        $(impl Gamut for $rgb {
+            fn from_oklab(color: Oklab) -> Self {
+                color.into_color_unclamped()
+            }
+
+            fn into_oklab(self) -> Oklab {
+                self.into_color_unclamped()
+            }
+
+            fn channels(&self) -> [f32; 3] {
+                [self.red, self.green, self.blue]
+            }
+
+            fn into_color_with_alpha(self, alpha: f32) -> Color {
+                Color::$variant(self.with_alpha(alpha))
+            }
+        })+
+    };
+}
+
+impl_gamut!(
+    Srgb => Rgba,
+    DisplayP3 => DisplayP3a,
+    Rec2020 => Rec2020a,
+    A98Rgb => A98Rgba,
+);
+
+// ProPhoto is the only D50 based space, so it needs to be adapted on the way
+impl Gamut for ProPhotoRgb {
+    fn from_oklab(color: Oklab) -> Self {
+        let xyz: Xyz = color.into_color_unclamped();
+
+        XyzD50::adapt_from(xyz).into_color_unclamped()
+    }
+
+    fn into_oklab(self) -> Oklab {
+        let xyz: XyzD50 = self.into_color_unclamped();
+
+        Xyz::adapt_from(xyz).into_color_unclamped()
+    }
+
+    fn channels(&self) -> [f32; 3] {
+        [self.red, self.green, self.blue]
+    }
+
+    fn into_color_with_alpha(self, alpha: f32) -> Color {
+        Color::ProPhotoRgba(self.with_alpha(alpha))
+    }
+}
+
+/// Maps `color` into the gamut of `G`, colors already in gamut are converted as is
+pub fn map<G: Gamut>(color: Oklab, mapping: GamutMapping) -> G {
+    let rgb = G::from_oklab(color);
+
+    if rgb.is_in_gamut() {
+        return rgb.clamp();
+    }
+
+    match mapping {
+        GamutMapping::Clip => rgb.clamp(),
+        GamutMapping::Css => map_css(color),
+    }
+}
+
+/// Binary search for the chroma where the clipped color is within a JND of the unclipped one
+fn map_css<G: Gamut>(origin: Oklab) -> G {
+    let mut current = Oklch::from_color_unclamped(origin);
+
+    if current.l >= 1.0 {
+        return G::from_oklab(Oklab::new(1.0, 0.0, 0.0)).clamp();
+    }
+
+    if current.l <= 0.0 {
+        return G::from_oklab(Oklab::new(0.0, 0.0, 0.0)).clamp();
+    }
+
+    let clip = |color: Oklch| {
+        let clipped = G::from_oklab(color.into_color_unclamped()).clamp();
+        let delta = delta_eok(clipped.into_oklab(), color.into_color_unclamped());
+
+        (clipped, delta)
+    };
+
+    let (mut clipped, delta) = clip(current);
+
+    if delta < JND {
+        return clipped;
+    }
+
+    let mut min = 0.0;
+    let mut max = current.chroma;
+    let mut min_in_gamut = true;
+
+    while max - min > EPSILON {
+        current.chroma = (min + max) / 2.0;
+
+        if min_in_gamut && G::from_oklab(current.into_color_unclamped()).is_in_gamut() {
+            min = current.chroma;
+            continue;
+        }
+
+        let delta;
+        (clipped, delta) = clip(current);
+
+        if delta < JND {
+            if JND - delta < EPSILON {
+                break;
+            }
+
+            min_in_gamut = false;
+            min = current.chroma;
+        } else {
+            max = current.chroma;
+        }
+    }
+
+    clipped
+}
+
+pub(crate) fn delta_eok(a: Oklab, b: Oklab) -> f32 {
+    ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use palette::IntoColor;
+
+    fn display_p3_red() -> Oklab {
+        Xyz::from_color_unclamped(DisplayP3::new(1.0, 0.0, 0.0)).into_color()
+    }
+
+    #[test]
+    fn display_p3_red_into_srgb() {
+        let origin = Oklch::from_color_unclamped(display_p3_red());
+        let mapped: Srgb = map(display_p3_red(), GamutMapping::Css);
+        let oklch = Oklch::from_color_unclamped(mapped.into_oklab());
+
+        // chroma is reduced until the clipped color is within a JND, the hue stays
+        assert!(mapped.is_in_gamut());
+        assert_eq!(mapped.red, 1.0);
+        assert!(mapped.green > 0.0 && mapped.blue > 0.0);
+        assert!(oklch.chroma < origin.chroma);
+        assert!((oklch.hue.into_degrees() - origin.hue.into_degrees()).abs() < 0.1);
+    }
+
+    #[test]
+    fn display_p3_red_clipped() {
+        let clipped: Srgb = map(display_p3_red(), GamutMapping::Clip);
+
+        assert_eq!(clipped, Srgb::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn in_gamut_is_untouched() {
+        let color = Srgb::new(0.2, 0.4, 0.6);
+        let mapped: Srgb = map(color.into_oklab(), GamutMapping::Css);
+
+        assert!(delta_eok(mapped.into_oklab(), color.into_oklab()) < EPSILON);
+    }
+}
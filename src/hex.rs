@@ -0,0 +1,168 @@
+//! Hex output formats with modifiers
+//!
+//! A hex format is one of `hex`, `hexa` or `ahex` followed by any of these
+//! modifiers separated by `-`, e.g. `hexa-lower-short` or `hex-nohash`
+//!
+//! - `lower` / `upper` case of the digits, upper is the default
+//! - `short` emits `#rgb` / `#rgba` when it's lossless
+//! - `auto` only emits alpha when the color isn't opaque
+//! - `bgr` swaps red and blue, `#bbggrr`
+//! - `nohash` leaves out the `#`
+//! - `0x` uses `0x` instead of `#`, not with `hexa` since `0x` and 8 digits
+//!   is read back as `0xAARRGGBB`
+
+use palette::Srgba;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum HexAlpha {
+    /// `#rrggbb`
+    #[default]
+    None,
+    /// `#rrggbbaa`
+    Rgba,
+    /// `#aarrggbb`
+    Argb,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum HexPrefix {
+    #[default]
+    Hash,
+    ZeroX,
+    None,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HexFormat {
+    pub alpha: HexAlpha,
+    pub prefix: HexPrefix,
+    pub lowercase: bool,
+    pub short: bool,
+    pub auto_alpha: bool,
+    pub bgr: bool,
+}
+
+impl HexFormat {
+    pub fn new(alpha: HexAlpha) -> Self {
+        Self {
+            alpha,
+            ..Default::default()
+        }
+    }
+
+    /// Parses a format like `hexa-lower-short`, returns `None` if it isn't a hex format
+    pub fn parse(format: &str) -> Option<Self> {
+        let mut modifiers = format.split('-');
+
+        let mut hex = match modifiers.next()? {
+            "hex" => Self::new(HexAlpha::None),
+            "hexa" => Self::new(HexAlpha::Rgba),
+            "ahex" => Self::new(HexAlpha::Argb),
+            _ => return None,
+        };
+
+        for modifier in modifiers {
+            hex.modifier(modifier)?;
+        }
+
+        Some(hex)
+    }
+
+    /// Applies a single modifier, returns `None` if it's unknown
+    pub fn modifier(&mut self, modifier: &str) -> Option<()> {
+        match modifier {
+            "lower" => self.lowercase = true,
+            "upper" => self.lowercase = false,
+            "short" => self.short = true,
+            "bgr" => self.bgr = true,
+            "auto" if self.alpha != HexAlpha::None => self.auto_alpha = true,
+            "hash" => self.prefix = HexPrefix::Hash,
+            "nohash" => self.prefix = HexPrefix::None,
+            "0x" if self.alpha != HexAlpha::Rgba => self.prefix = HexPrefix::ZeroX,
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    pub fn format(&self, color: Srgba<u8>) -> String {
+        let (r, g, b, a) = color.into_components();
+        let (r, b) = if self.bgr { (b, r) } else { (r, b) };
+
+        let channels = match self.alpha {
+            HexAlpha::Rgba | HexAlpha::Argb if self.auto_alpha && a == u8::MAX => vec![r, g, b],
+            HexAlpha::None => vec![r, g, b],
+            HexAlpha::Rgba => vec![r, g, b, a],
+            HexAlpha::Argb => vec![a, r, g, b],
+        };
+
+        // every channel has to be a repeated digit, 0x11 * n
+        let short = self.short && channels.iter().all(|c| c % 17 == 0);
+
+        let mut dst = String::from(match self.prefix {
+            HexPrefix::Hash => "#",
+            HexPrefix::ZeroX => "0x",
+            HexPrefix::None => "",
+        });
+
+        for c in channels {
+            let digits = match (short, self.lowercase) {
+                (true, false) => format!("{:X}", c / 17),
+                (true, true) => format!("{:x}", c / 17),
+                (false, false) => format!("{c:02X}"),
+                (false, true) => format!("{c:02x}"),
+            };
+
+            dst.push_str(&digits);
+        }
+
+        dst
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(format: &str, [r, g, b, a]: [u8; 4]) -> String {
+        HexFormat::parse(format)
+            .unwrap()
+            .format(Srgba::new(r, g, b, a))
+    }
+
+    #[test]
+    fn parse_modifiers() {
+        let hex = HexFormat::parse("hexa-lower-short-auto-nohash").unwrap();
+        assert_eq!(hex.alpha, HexAlpha::Rgba);
+        assert_eq!(hex.prefix, HexPrefix::None);
+        assert!(hex.lowercase && hex.short && hex.auto_alpha);
+        assert_eq!(HexFormat::parse("hex"), Some(HexFormat::default()));
+        assert_eq!(HexFormat::parse("hex-auto"), None);
+        assert_eq!(HexFormat::parse("hex-wide"), None);
+        assert_eq!(HexFormat::parse("hexa-0x"), None);
+        assert_eq!(HexFormat::parse("hexadecimal"), None);
+    }
+
+    #[test]
+    fn alpha_position() {
+        let color = [0x12, 0x34, 0x56, 0x78];
+        assert_eq!(format("hex", color), "#123456");
+        assert_eq!(format("hexa", color), "#12345678");
+        assert_eq!(format("ahex", color), "#78123456");
+        assert_eq!(format("hexa-auto", color), "#12345678");
+        assert_eq!(format("ahex-auto", [0x12, 0x34, 0x56, 0xff]), "#123456");
+    }
+
+    #[test]
+    fn case_prefix_and_short_form() {
+        assert_eq!(format("hex-lower", [0xab, 0xcd, 0xef, 0xff]), "#abcdef");
+        assert_eq!(format("hex-nohash", [0xab, 0xcd, 0xef, 0xff]), "ABCDEF");
+        assert_eq!(format("hex-0x", [0xab, 0xcd, 0xef, 0xff]), "0xABCDEF");
+        assert_eq!(
+            format("hexa-short-lower", [0xaa, 0xbb, 0xcc, 0xdd]),
+            "#abcd"
+        );
+        assert_eq!(format("hex-short", [0xaa, 0xbb, 0xcd, 0xff]), "#AABBCD");
+        assert_eq!(format("hexa-bgr", [0x12, 0x34, 0x56, 0x78]), "#56341278");
+    }
+}
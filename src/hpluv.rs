@@ -0,0 +1,206 @@
+//! HPLuv, the pastel sibling of HSLuv, see <https://www.hsluv.org>
+//!
+//! palette only ships HSLuv, HPLuv stretches chroma to the largest value that
+//! stays in the sRGB gamut at every hue of a lightness instead of the current
+//! one, so saturation 100 is not the most colorful color but hue can be changed
+//! freely without leaving the gamut
+
+use palette::{Lchuv, Lchuva, WithAlpha};
+
+/// Rows of the XYZ to linear sRGB matrix
+const M: [[f64; 3]; 3] = [
+    [3.240969941904521, -1.537383177570093, -0.498610760293],
+    [-0.96924363628087, 1.87596750150772, 0.041555057407175],
+    [0.055630079696993, -0.20397695888897, 1.056971514242878],
+];
+const KAPPA: f64 = 903.2962962;
+const EPSILON: f64 = 0.0088564516;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hpluv {
+    /// Hue in degrees
+    pub hue: f32,
+    /// `0..=100`
+    pub saturation: f32,
+    /// `0..=100`
+    pub l: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hpluva {
+    pub hue: f32,
+    pub saturation: f32,
+    pub l: f32,
+    pub alpha: f32,
+}
+
+impl Hpluv {
+    pub fn new(hue: f32, saturation: f32, l: f32) -> Self {
+        Self { hue, saturation, l }
+    }
+
+    pub fn with_alpha(self, alpha: f32) -> Hpluva {
+        let Self { hue, saturation, l } = self;
+
+        Hpluva {
+            hue,
+            saturation,
+            l,
+            alpha,
+        }
+    }
+
+    pub fn into_components(self) -> (f32, f32, f32) {
+        (self.hue, self.saturation, self.l)
+    }
+}
+
+impl Hpluva {
+    pub fn without_alpha(self) -> Hpluv {
+        Hpluv::new(self.hue, self.saturation, self.l)
+    }
+
+    pub fn into_components(self) -> (f32, f32, f32, f32) {
+        (self.hue, self.saturation, self.l, self.alpha)
+    }
+}
+
+impl From<[f32; 3]> for Hpluv {
+    fn from([h, s, l]: [f32; 3]) -> Self {
+        Self::new(h, s, l)
+    }
+}
+
+impl From<[f32; 4]> for Hpluva {
+    fn from([h, s, l, a]: [f32; 4]) -> Self {
+        Hpluv::new(h, s, l).with_alpha(a)
+    }
+}
+
+impl From<Hpluv> for Hpluva {
+    fn from(color: Hpluv) -> Self {
+        color.with_alpha(1.0)
+    }
+}
+
+impl From<Lchuv> for Hpluv {
+    fn from(color: Lchuv) -> Self {
+        let hue = color.hue.into_positive_degrees();
+
+        if color.l > 99.99999 {
+            return Self::new(hue, 0.0, 100.0);
+        }
+
+        if color.l < 0.00001 {
+            return Self::new(hue, 0.0, 0.0);
+        }
+
+        Self::new(
+            hue,
+            color.chroma / max_safe_chroma(color.l) * 100.0,
+            color.l,
+        )
+    }
+}
+
+impl From<Hpluv> for Lchuv {
+    fn from(color: Hpluv) -> Self {
+        if color.l > 99.99999 {
+            return Lchuv::new(100.0, 0.0, color.hue);
+        }
+
+        if color.l < 0.00001 {
+            return Lchuv::new(0.0, 0.0, color.hue);
+        }
+
+        let chroma = max_safe_chroma(color.l) / 100.0 * color.saturation;
+
+        Lchuv::new(color.l, chroma, color.hue)
+    }
+}
+
+impl From<Lchuva> for Hpluva {
+    fn from(color: Lchuva) -> Self {
+        Hpluv::from(color.color).with_alpha(color.alpha)
+    }
+}
+
+impl From<Hpluva> for Lchuva {
+    fn from(color: Hpluva) -> Self {
+        Lchuv::from(color.without_alpha()).with_alpha(color.alpha)
+    }
+}
+
+/// Largest chroma that is in the sRGB gamut for every hue at lightness `l`,
+/// the distance to the closest of the six gamut boundary lines in the uv plane
+fn max_safe_chroma(l: f32) -> f32 {
+    let l = f64::from(l);
+    let sub1 = (l + 16.0).powi(3) / 1560896.0;
+    let sub2 = if sub1 > EPSILON { sub1 } else { l / KAPPA };
+
+    let mut min = f64::MAX;
+
+    for [m1, m2, m3] in M {
+        for t in [0.0, 1.0] {
+            let top1 = (284517.0 * m1 - 94839.0 * m3) * sub2;
+            let top2 =
+                (838422.0 * m3 + 769860.0 * m2 + 731718.0 * m1) * l * sub2 - 769860.0 * t * l;
+            let bottom = (632260.0 * m3 - 126452.0 * m2) * sub2 + 126452.0 * t;
+
+            let slope = top1 / bottom;
+            let intercept = top2 / bottom;
+
+            min = min.min(intercept.abs() / (slope * slope + 1.0).sqrt());
+        }
+    }
+
+    min as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// LCHuv and HPLuv saturation of `#ff0000`, `#0000ff` and `#00ff00` from the hsluv snapshot
+    const REFERENCE: [([f32; 3], f32); 3] = [
+        ([53.237_12, 179.041_4, 12.177_05], 426.746_8),
+        ([32.300_87, 130.689, 265.874_3], 513.412_7),
+        ([87.737_03, 135.781, 127.715], 490.145_05),
+    ];
+
+    #[test]
+    fn max_safe_chroma_matches_hsluv() {
+        for ([l, c, _], s) in REFERENCE {
+            let expected = c / s * 100.0;
+
+            assert!(
+                (max_safe_chroma(l) - expected).abs() < 0.01,
+                "{l}: {} != {expected}",
+                max_safe_chroma(l)
+            );
+        }
+    }
+
+    #[test]
+    fn max_safe_chroma_vanishes_at_black_and_white() {
+        assert_eq!(max_safe_chroma(0.0), 0.0);
+        assert!(max_safe_chroma(100.0) < 1e-6);
+    }
+
+    #[test]
+    fn lchuv_round_trip() {
+        for ([l, c, h], s) in REFERENCE {
+            let hpluv = Hpluv::from(Lchuv::new(l, c, h));
+
+            assert!(
+                (hpluv.saturation - s).abs() < 0.1,
+                "{l}: {} != {s}",
+                hpluv.saturation
+            );
+
+            let lchuv = Lchuv::from(hpluv);
+
+            assert!((lchuv.chroma - c).abs() < 0.001);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Color temperature, `kelvin(6500)` or `6500K` as input and `kelvin` as output
+//!
+//! Input is the blackbody color at that temperature, from the Planckian locus
+//! approximation of Kim et al. which covers `1667..=25000` kelvin, scaled so
+//! its brightest linear sRGB channel is 1. Output is the correlated color
+//! temperature of McCamy's approximation, which is only meaningful for colors
+//! near the locus in roughly `2000..=12500` kelvin
+
+use crate::Result;
+use crate::parser::{ParseError, offset_of};
+use palette::convert::FromColorUnclamped;
+use palette::{LinSrgb, Xyz, Yxy};
+
+pub const MIN: f32 = 1667.0;
+pub const MAX: f32 = 25000.0;
+
+/// Blackbody color at `kelvin`, `None` outside of [`MIN`] and [`MAX`]
+pub fn blackbody(kelvin: f32) -> Option<Xyz> {
+    if !(MIN..=MAX).contains(&kelvin) {
+        return None;
+    }
+
+    let t = kelvin;
+    let x = match t {
+        ..=4000.0 => {
+            -0.266_123_9e9 / t.powi(3) - 0.234_358_9e6 / t.powi(2) + 0.877_695_6e3 / t + 0.179_910
+        }
+        _ => -3.025_846_9e9 / t.powi(3) + 2.107_038e6 / t.powi(2) + 0.222_634_7e3 / t + 0.240_390,
+    };
+
+    let y = match t {
+        ..=2222.0 => {
+            -1.106_381_4 * x.powi(3) - 1.348_110_2 * x.powi(2) + 2.185_558_3 * x - 0.202_196_83
+        }
+        ..=4000.0 => {
+            -0.954_947_6 * x.powi(3) - 1.374_185_9 * x.powi(2) + 2.091_37 * x - 0.167_488_67
+        }
+        _ => 3.081_758 * x.powi(3) - 5.873_387 * x.powi(2) + 3.751_13 * x - 0.370_014_83,
+    };
+
+    let xyz = Xyz::from_color_unclamped(Yxy::new(x, y, 1.0));
+    let rgb = LinSrgb::from_color_unclamped(xyz);
+    let max = rgb.red.max(rgb.green).max(rgb.blue);
+
+    Some(xyz * (1.0 / max))
+}
+
+/// Correlated color temperature, black has no chromaticity and is taken as D65
+pub fn cct(color: Yxy) -> f32 {
+    let (x, y) = match color.luma > 0.0 {
+        true => (color.x, color.y),
+        false => (0.3127, 0.3290),
+    };
+
+    let n = (x - 0.3320) / (0.1858 - y);
+
+    449.0 * n.powi(3) + 3525.0 * n.powi(2) + 6823.3 * n + 5520.33
+}
+
+/// Returns `None` if `src` isn't a temperature at all
+pub fn parse(src: &str) -> Option<Result<Xyz>> {
+    let value = match src.split_once('(') {
+        Some((name, value)) if name.trim().eq_ignore_ascii_case("kelvin") => {
+            match value.trim_end().strip_suffix(')') {
+                Some(value) => value.trim(),
+                None => return Some(Err(ParseError::expected(src, src.len(), "`)`").into())),
+            }
+        }
+        Some(_) => return None,
+        None => src
+            .strip_suffix(['K', 'k'])
+            .filter(|value| value.starts_with(|c: char| c.is_ascii_digit()))?,
+    };
+
+    let xyz = value.parse().ok().and_then(blackbody).ok_or_else(|| {
+        ParseError::expected(src, offset_of(src, value), "1667 to 25000 kelvin").into()
+    });
+
+    Some(xyz)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// CIE 1931 chromaticity and correlated color temperature of illuminants A, D50 and D65
+    const ILLUMINANTS: [(f32, f32, f32); 3] = [
+        (0.44757, 0.40745, 2856.0),
+        (0.34567, 0.35850, 5003.0),
+        (0.31271, 0.32902, 6504.0),
+    ];
+
+    #[test]
+    fn cct_of_illuminants() {
+        for (x, y, kelvin) in ILLUMINANTS {
+            let cct = cct(Yxy::new(x, y, 1.0));
+
+            assert!((cct - kelvin).abs() < 5.0, "{kelvin}: {cct}");
+        }
+    }
+
+    #[test]
+    fn cct_of_black_is_d65() {
+        assert!((cct(Yxy::new(0.0, 0.0, 0.0)) - 6504.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn blackbody_of_illuminant_a() {
+        // A is a blackbody at 2856 K
+        let (x, y, kelvin) = ILLUMINANTS[0];
+        let yxy = Yxy::from_color_unclamped(blackbody(kelvin).unwrap());
+
+        assert!(
+            (yxy.x - x).abs() < 0.001 && (yxy.y - y).abs() < 0.001,
+            "{yxy:?}"
+        );
+    }
+
+    #[test]
+    fn blackbody_is_normalized() {
+        for kelvin in [MIN, 2856.0, 6500.0, MAX] {
+            let rgb = LinSrgb::from_color_unclamped(blackbody(kelvin).unwrap());
+
+            assert!((rgb.red.max(rgb.green).max(rgb.blue) - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn blackbody_range() {
+        assert!(blackbody(MIN - 1.0).is_none());
+        assert!(blackbody(MAX + 1.0).is_none());
+    }
+
+    #[test]
+    fn parse_notations() {
+        assert!(parse("6500K").unwrap().is_ok());
+        assert!(parse("2856k").unwrap().is_ok());
+        assert!(parse("Kelvin( 4000 )").unwrap().is_ok());
+        assert!(parse("kelvin(1000)").unwrap().is_err());
+        assert!(parse("kelvin()").unwrap().is_err());
+        assert!(parse("K").is_none());
+        assert!(parse("rgb(1 2 3)").is_none());
+        assert!(parse("black").is_none());
+    }
+}
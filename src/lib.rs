@@ -2,8 +2,22 @@ use std::path::PathBuf;
 use palette::rgb::FromHexError;
 use thiserror::Error;
 
+pub mod ansi;
+pub mod cmyk;
 pub mod color;
 pub mod config;
+pub mod css;
+pub mod encoding;
+pub mod format;
+pub mod gamut;
+pub mod hex;
+pub mod hpluv;
+pub mod kelvin;
+pub mod names;
+pub mod packed;
+pub mod parser;
+pub mod shader;
+pub mod template;
 
 pub type Result<T = (), E = Error> = std::result::Result<T, E>;
 
@@ -48,6 +62,9 @@ pub enum Error {
     #[error("Failed to parse color: {0}")]
     FailedToParseColor(String),
 
-    #[error("Failed to parse params: {0}")]
-    FailedToParseColorParams(String),
+    #[error(transparent)]
+    ParseError(#[from] parser::ParseError),
+
+    #[error("No named color matches: {0}")]
+    NoNamedColor(String),
 }
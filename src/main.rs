@@ -86,6 +86,7 @@ fn main() -> colorutil::Result<()> {
     let config = load_config::<ConfigBase>("config")?;
     let config = config.parse()?;
     let palette = &config.palettes[&config.palette];
+    let options = config.format_options();
 
     match args.command {
         CliCommand::Completions { shell } => {
@@ -101,7 +102,7 @@ fn main() -> colorutil::Result<()> {
             ..
         } => {
             let text = std::fs::read_to_string(&src)?;
-            let text = parse_text(text, config.prefix, config.suffix, palette)?;
+            let text = parse_text(text, &config.prefix, &config.suffix, palette, &options)?;
 
             match dst {
                 None => {
@@ -144,10 +145,10 @@ fn main() -> colorutil::Result<()> {
             suffix,
             ..
         } => {
-            let prefix = prefix.unwrap_or(config.prefix);
-            let suffix = suffix.unwrap_or(config.suffix);
+            let prefix = prefix.as_deref().unwrap_or(&config.prefix);
+            let suffix = suffix.as_deref().unwrap_or(&config.suffix);
 
-            let text = parse_text(text, prefix, suffix, palette)?;
+            let text = parse_text(text, prefix, suffix, palette, &options)?;
 
             println!("{}", text);
         }
@@ -0,0 +1,1959 @@
+//! Named color databases and the reverse lookup of names
+//!
+//! `css` is the 148 CSS names, `x11` the full X11 `rgb.txt` set including
+//! numbered variants like `slateblue3` and `xkcd` the 949 names of the xkcd
+//! color survey. Any other database is read from `<name>.txt` in the config
+//! directory, either in the X11 `rgb.txt` format (`106 90 205  SlateBlue`)
+//! or the xkcd one (`slate blue  #5b7c99`).
+//! Names are matched ignoring case and spaces, `Slate Blue` is `slateblue`
+//!
+//! The `name` output format emits the perceptually nearest name, `name(exact)`
+//! fails unless the color is exactly one of them. Alpha is ignored and colors
+//! are gamut mapped into sRGB first. Aliases like `gray` and `grey` resolve to
+//! the one that comes first alphabetically
+
+use crate::config::{DEFAULT_COLORS, Palette, get_config_dir};
+use crate::gamut::delta_eok;
+use crate::{Error, Result};
+use palette::{IntoColor, Oklab, Srgb};
+use std::borrow::Cow;
+
+/// Databases used when the config doesn't list any
+pub const DEFAULT_NAMES: &[NameDatabase] = &[NameDatabase::Css];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NameDatabase {
+    Css,
+    X11,
+    Xkcd,
+    /// Loaded from a file in the config directory
+    File {
+        name: String,
+        colors: Vec<(String, Srgb<u8>)>,
+    },
+}
+
+impl NameDatabase {
+    /// Builtin database or `<name>.txt` in the config directory
+    pub fn load(name: &str) -> Result<Self> {
+        match name {
+            "css" => return Ok(Self::Css),
+            "x11" => return Ok(Self::X11),
+            "xkcd" => return Ok(Self::Xkcd),
+            _ => {}
+        }
+
+        let path = get_config_dir()?.join(name).with_extension("txt");
+
+        if !path.is_file() {
+            return Err(Error::NotFile(path));
+        }
+
+        let colors = parse_rgb_txt(&std::fs::read_to_string(path)?)?;
+
+        Ok(Self::File {
+            name: name.to_owned(),
+            colors,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Css => "css",
+            Self::X11 => "x11",
+            Self::Xkcd => "xkcd",
+            Self::File { name, .. } => name,
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<Srgb<u8>> {
+        let key = key(name);
+
+        match self {
+            Self::Css => palette::named::from_str(&key),
+            Self::X11 => X11_COLORS
+                .binary_search_by_key(&key.as_str(), |(name, _)| name)
+                .ok()
+                .map(|i| Srgb::from(X11_COLORS[i].1)),
+            Self::Xkcd => {
+                let start = XKCD_COLORS.partition_point(|(name, _)| self::key(name) < key);
+                let same_key = XKCD_COLORS[start..]
+                    .iter()
+                    .take_while(|(name, _)| self::key(name) == key);
+
+                // `eggshell` and `egg shell` are different colors, the exact name wins
+                let lowercase = name.to_lowercase();
+                let (_, rgb) = same_key
+                    .clone()
+                    .find(|(name, _)| *name == lowercase)
+                    .or_else(|| same_key.clone().next())?;
+
+                Some(Srgb::from(*rgb))
+            }
+            Self::File { colors, .. } => colors
+                .iter()
+                .find_map(|(name, color)| (self::key(name) == key).then_some(*color)),
+        }
+    }
+
+    pub fn colors(&self) -> Box<dyn Iterator<Item = (&str, Srgb<u8>)> + '_> {
+        match self {
+            Self::Css => Box::new(DEFAULT_COLORS.into_iter().filter_map(|(name, _)| {
+                let Cow::Borrowed(name) = name else {
+                    return None;
+                };
+
+                palette::named::from_str(name).map(|value| (name, value))
+            })),
+            Self::X11 => Box::new(
+                X11_COLORS
+                    .iter()
+                    .map(|(name, rgb)| (*name, Srgb::from(*rgb))),
+            ),
+            Self::Xkcd => Box::new(
+                XKCD_COLORS
+                    .iter()
+                    .map(|(name, rgb)| (*name, Srgb::from(*rgb))),
+            ),
+            Self::File { colors, .. } => {
+                Box::new(colors.iter().map(|(name, color)| (name.as_str(), *color)))
+            }
+        }
+    }
+
+    /// The database as a palette that can be inherited from, names are
+    /// lowercase without spaces
+    pub fn palette(&self) -> Palette<'static> {
+        self.colors()
+            .map(|(name, color)| {
+                let (r, g, b) = color.into_components();
+
+                (key(name).into(), format!("#{r:02X}{g:02X}{b:02X}").into())
+            })
+            .collect()
+    }
+}
+
+/// Name of the color nearest to `color` by Oklab distance over all `databases`,
+/// `None` if `exact` and no name has exactly this value
+pub fn nearest_name(color: Srgb<u8>, exact: bool, databases: &[NameDatabase]) -> Option<&str> {
+    let mut names = databases.iter().flat_map(NameDatabase::colors);
+
+    if exact {
+        return names.find_map(|(name, value)| (value == color).then_some(name));
+    }
+
+    let oklab = |color: Srgb<u8>| -> Oklab { color.into_format::<f32>().into_color() };
+    let target = oklab(color);
+
+    names
+        .map(|(name, value)| (delta_eok(target, oklab(value)), name))
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, name)| name)
+}
+
+fn key(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Lines of `r g b name` or `name #rrggbb`, `!` and `#` start a comment line,
+/// so does the `License:` header of the xkcd file
+fn parse_rgb_txt(src: &str) -> Result<Vec<(String, Srgb<u8>)>> {
+    let mut colors = Vec::new();
+
+    for line in src.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with(['!', '#']) || line.starts_with("License:") {
+            continue;
+        }
+
+        let err = || Error::FailedToParseColor(line.to_owned());
+
+        let color = match line.rsplit_once('#') {
+            Some((name, hex)) => {
+                let rgb = hex.trim().parse::<Srgb<u8>>().map_err(|_| err())?;
+
+                (name.trim().to_owned(), rgb)
+            }
+            None => {
+                let mut rest = line;
+                let mut rgb = [0u8; 3];
+
+                for channel in &mut rgb {
+                    let (value, tail) = rest.split_once(char::is_whitespace).ok_or_else(err)?;
+                    *channel = value.parse().map_err(|_| err())?;
+                    rest = tail.trim_start();
+                }
+
+                if rest.is_empty() {
+                    return Err(err());
+                }
+
+                (rest.to_owned(), Srgb::from(rgb))
+            }
+        };
+
+        colors.push(color);
+    }
+
+    Ok(colors)
+}
+
+/// X11 `rgb.txt`, lowercase without spaces and sorted for the lookup
+#[rustfmt::skip]
+const X11_COLORS: [(&str, [u8; 3]); 658] = [
+    ("aliceblue", [240, 248, 255]),
+    ("antiquewhite", [250, 235, 215]),
+    ("antiquewhite1", [255, 239, 219]),
+    ("antiquewhite2", [238, 223, 204]),
+    ("antiquewhite3", [205, 192, 176]),
+    ("antiquewhite4", [139, 131, 120]),
+    ("aquamarine", [127, 255, 212]),
+    ("aquamarine1", [127, 255, 212]),
+    ("aquamarine2", [118, 238, 198]),
+    ("aquamarine3", [102, 205, 170]),
+    ("aquamarine4", [69, 139, 116]),
+    ("azure", [240, 255, 255]),
+    ("azure1", [240, 255, 255]),
+    ("azure2", [224, 238, 238]),
+    ("azure3", [193, 205, 205]),
+    ("azure4", [131, 139, 139]),
+    ("beige", [245, 245, 220]),
+    ("bisque", [255, 228, 196]),
+    ("bisque1", [255, 228, 196]),
+    ("bisque2", [238, 213, 183]),
+    ("bisque3", [205, 183, 158]),
+    ("bisque4", [139, 125, 107]),
+    ("black", [0, 0, 0]),
+    ("blanchedalmond", [255, 235, 205]),
+    ("blue", [0, 0, 255]),
+    ("blue1", [0, 0, 255]),
+    ("blue2", [0, 0, 238]),
+    ("blue3", [0, 0, 205]),
+    ("blue4", [0, 0, 139]),
+    ("blueviolet", [138, 43, 226]),
+    ("brown", [165, 42, 42]),
+    ("brown1", [255, 64, 64]),
+    ("brown2", [238, 59, 59]),
+    ("brown3", [205, 51, 51]),
+    ("brown4", [139, 35, 35]),
+    ("burlywood", [222, 184, 135]),
+    ("burlywood1", [255, 211, 155]),
+    ("burlywood2", [238, 197, 145]),
+    ("burlywood3", [205, 170, 125]),
+    ("burlywood4", [139, 115, 85]),
+    ("cadetblue", [95, 158, 160]),
+    ("cadetblue1", [152, 245, 255]),
+    ("cadetblue2", [142, 229, 238]),
+    ("cadetblue3", [122, 197, 205]),
+    ("cadetblue4", [83, 134, 139]),
+    ("chartreuse", [127, 255, 0]),
+    ("chartreuse1", [127, 255, 0]),
+    ("chartreuse2", [118, 238, 0]),
+    ("chartreuse3", [102, 205, 0]),
+    ("chartreuse4", [69, 139, 0]),
+    ("chocolate", [210, 105, 30]),
+    ("chocolate1", [255, 127, 36]),
+    ("chocolate2", [238, 118, 33]),
+    ("chocolate3", [205, 102, 29]),
+    ("chocolate4", [139, 69, 19]),
+    ("coral", [255, 127, 80]),
+    ("coral1", [255, 114, 86]),
+    ("coral2", [238, 106, 80]),
+    ("coral3", [205, 91, 69]),
+    ("coral4", [139, 62, 47]),
+    ("cornflowerblue", [100, 149, 237]),
+    ("cornsilk", [255, 248, 220]),
+    ("cornsilk1", [255, 248, 220]),
+    ("cornsilk2", [238, 232, 205]),
+    ("cornsilk3", [205, 200, 177]),
+    ("cornsilk4", [139, 136, 120]),
+    ("cyan", [0, 255, 255]),
+    ("cyan1", [0, 255, 255]),
+    ("cyan2", [0, 238, 238]),
+    ("cyan3", [0, 205, 205]),
+    ("cyan4", [0, 139, 139]),
+    ("darkblue", [0, 0, 139]),
+    ("darkcyan", [0, 139, 139]),
+    ("darkgoldenrod", [184, 134, 11]),
+    ("darkgoldenrod1", [255, 185, 15]),
+    ("darkgoldenrod2", [238, 173, 14]),
+    ("darkgoldenrod3", [205, 149, 12]),
+    ("darkgoldenrod4", [139, 101, 8]),
+    ("darkgray", [169, 169, 169]),
+    ("darkgreen", [0, 100, 0]),
+    ("darkgrey", [169, 169, 169]),
+    ("darkkhaki", [189, 183, 107]),
+    ("darkmagenta", [139, 0, 139]),
+    ("darkolivegreen", [85, 107, 47]),
+    ("darkolivegreen1", [202, 255, 112]),
+    ("darkolivegreen2", [188, 238, 104]),
+    ("darkolivegreen3", [162, 205, 90]),
+    ("darkolivegreen4", [110, 139, 61]),
+    ("darkorange", [255, 140, 0]),
+    ("darkorange1", [255, 127, 0]),
+    ("darkorange2", [238, 118, 0]),
+    ("darkorange3", [205, 102, 0]),
+    ("darkorange4", [139, 69, 0]),
+    ("darkorchid", [153, 50, 204]),
+    ("darkorchid1", [191, 62, 255]),
+    ("darkorchid2", [178, 58, 238]),
+    ("darkorchid3", [154, 50, 205]),
+    ("darkorchid4", [104, 34, 139]),
+    ("darkred", [139, 0, 0]),
+    ("darksalmon", [233, 150, 122]),
+    ("darkseagreen", [143, 188, 143]),
+    ("darkseagreen1", [193, 255, 193]),
+    ("darkseagreen2", [180, 238, 180]),
+    ("darkseagreen3", [155, 205, 155]),
+    ("darkseagreen4", [105, 139, 105]),
+    ("darkslateblue", [72, 61, 139]),
+    ("darkslategray", [47, 79, 79]),
+    ("darkslategray1", [151, 255, 255]),
+    ("darkslategray2", [141, 238, 238]),
+    ("darkslategray3", [121, 205, 205]),
+    ("darkslategray4", [82, 139, 139]),
+    ("darkslategrey", [47, 79, 79]),
+    ("darkturquoise", [0, 206, 209]),
+    ("darkviolet", [148, 0, 211]),
+    ("debianred", [215, 7, 81]),
+    ("deeppink", [255, 20, 147]),
+    ("deeppink1", [255, 20, 147]),
+    ("deeppink2", [238, 18, 137]),
+    ("deeppink3", [205, 16, 118]),
+    ("deeppink4", [139, 10, 80]),
+    ("deepskyblue", [0, 191, 255]),
+    ("deepskyblue1", [0, 191, 255]),
+    ("deepskyblue2", [0, 178, 238]),
+    ("deepskyblue3", [0, 154, 205]),
+    ("deepskyblue4", [0, 104, 139]),
+    ("dimgray", [105, 105, 105]),
+    ("dimgrey", [105, 105, 105]),
+    ("dodgerblue", [30, 144, 255]),
+    ("dodgerblue1", [30, 144, 255]),
+    ("dodgerblue2", [28, 134, 238]),
+    ("dodgerblue3", [24, 116, 205]),
+    ("dodgerblue4", [16, 78, 139]),
+    ("firebrick", [178, 34, 34]),
+    ("firebrick1", [255, 48, 48]),
+    ("firebrick2", [238, 44, 44]),
+    ("firebrick3", [205, 38, 38]),
+    ("firebrick4", [139, 26, 26]),
+    ("floralwhite", [255, 250, 240]),
+    ("forestgreen", [34, 139, 34]),
+    ("gainsboro", [220, 220, 220]),
+    ("ghostwhite", [248, 248, 255]),
+    ("gold", [255, 215, 0]),
+    ("gold1", [255, 215, 0]),
+    ("gold2", [238, 201, 0]),
+    ("gold3", [205, 173, 0]),
+    ("gold4", [139, 117, 0]),
+    ("goldenrod", [218, 165, 32]),
+    ("goldenrod1", [255, 193, 37]),
+    ("goldenrod2", [238, 180, 34]),
+    ("goldenrod3", [205, 155, 29]),
+    ("goldenrod4", [139, 105, 20]),
+    ("gray", [190, 190, 190]),
+    ("gray0", [0, 0, 0]),
+    ("gray1", [3, 3, 3]),
+    ("gray10", [26, 26, 26]),
+    ("gray100", [255, 255, 255]),
+    ("gray11", [28, 28, 28]),
+    ("gray12", [31, 31, 31]),
+    ("gray13", [33, 33, 33]),
+    ("gray14", [36, 36, 36]),
+    ("gray15", [38, 38, 38]),
+    ("gray16", [41, 41, 41]),
+    ("gray17", [43, 43, 43]),
+    ("gray18", [46, 46, 46]),
+    ("gray19", [48, 48, 48]),
+    ("gray2", [5, 5, 5]),
+    ("gray20", [51, 51, 51]),
+    ("gray21", [54, 54, 54]),
+    ("gray22", [56, 56, 56]),
+    ("gray23", [59, 59, 59]),
+    ("gray24", [61, 61, 61]),
+    ("gray25", [64, 64, 64]),
+    ("gray26", [66, 66, 66]),
+    ("gray27", [69, 69, 69]),
+    ("gray28", [71, 71, 71]),
+    ("gray29", [74, 74, 74]),
+    ("gray3", [8, 8, 8]),
+    ("gray30", [77, 77, 77]),
+    ("gray31", [79, 79, 79]),
+    ("gray32", [82, 82, 82]),
+    ("gray33", [84, 84, 84]),
+    ("gray34", [87, 87, 87]),
+    ("gray35", [89, 89, 89]),
+    ("gray36", [92, 92, 92]),
+    ("gray37", [94, 94, 94]),
+    ("gray38", [97, 97, 97]),
+    ("gray39", [99, 99, 99]),
+    ("gray4", [10, 10, 10]),
+    ("gray40", [102, 102, 102]),
+    ("gray41", [105, 105, 105]),
+    ("gray42", [107, 107, 107]),
+    ("gray43", [110, 110, 110]),
+    ("gray44", [112, 112, 112]),
+    ("gray45", [115, 115, 115]),
+    ("gray46", [117, 117, 117]),
+    ("gray47", [120, 120, 120]),
+    ("gray48", [122, 122, 122]),
+    ("gray49", [125, 125, 125]),
+    ("gray5", [13, 13, 13]),
+    ("gray50", [127, 127, 127]),
+    ("gray51", [130, 130, 130]),
+    ("gray52", [133, 133, 133]),
+    ("gray53", [135, 135, 135]),
+    ("gray54", [138, 138, 138]),
+    ("gray55", [140, 140, 140]),
+    ("gray56", [143, 143, 143]),
+    ("gray57", [145, 145, 145]),
+    ("gray58", [148, 148, 148]),
+    ("gray59", [150, 150, 150]),
+    ("gray6", [15, 15, 15]),
+    ("gray60", [153, 153, 153]),
+    ("gray61", [156, 156, 156]),
+    ("gray62", [158, 158, 158]),
+    ("gray63", [161, 161, 161]),
+    ("gray64", [163, 163, 163]),
+    ("gray65", [166, 166, 166]),
+    ("gray66", [168, 168, 168]),
+    ("gray67", [171, 171, 171]),
+    ("gray68", [173, 173, 173]),
+    ("gray69", [176, 176, 176]),
+    ("gray7", [18, 18, 18]),
+    ("gray70", [179, 179, 179]),
+    ("gray71", [181, 181, 181]),
+    ("gray72", [184, 184, 184]),
+    ("gray73", [186, 186, 186]),
+    ("gray74", [189, 189, 189]),
+    ("gray75", [191, 191, 191]),
+    ("gray76", [194, 194, 194]),
+    ("gray77", [196, 196, 196]),
+    ("gray78", [199, 199, 199]),
+    ("gray79", [201, 201, 201]),
+    ("gray8", [20, 20, 20]),
+    ("gray80", [204, 204, 204]),
+    ("gray81", [207, 207, 207]),
+    ("gray82", [209, 209, 209]),
+    ("gray83", [212, 212, 212]),
+    ("gray84", [214, 214, 214]),
+    ("gray85", [217, 217, 217]),
+    ("gray86", [219, 219, 219]),
+    ("gray87", [222, 222, 222]),
+    ("gray88", [224, 224, 224]),
+    ("gray89", [227, 227, 227]),
+    ("gray9", [23, 23, 23]),
+    ("gray90", [229, 229, 229]),
+    ("gray91", [232, 232, 232]),
+    ("gray92", [235, 235, 235]),
+    ("gray93", [237, 237, 237]),
+    ("gray94", [240, 240, 240]),
+    ("gray95", [242, 242, 242]),
+    ("gray96", [245, 245, 245]),
+    ("gray97", [247, 247, 247]),
+    ("gray98", [250, 250, 250]),
+    ("gray99", [252, 252, 252]),
+    ("green", [0, 255, 0]),
+    ("green1", [0, 255, 0]),
+    ("green2", [0, 238, 0]),
+    ("green3", [0, 205, 0]),
+    ("green4", [0, 139, 0]),
+    ("greenyellow", [173, 255, 47]),
+    ("grey", [190, 190, 190]),
+    ("grey0", [0, 0, 0]),
+    ("grey1", [3, 3, 3]),
+    ("grey10", [26, 26, 26]),
+    ("grey100", [255, 255, 255]),
+    ("grey11", [28, 28, 28]),
+    ("grey12", [31, 31, 31]),
+    ("grey13", [33, 33, 33]),
+    ("grey14", [36, 36, 36]),
+    ("grey15", [38, 38, 38]),
+    ("grey16", [41, 41, 41]),
+    ("grey17", [43, 43, 43]),
+    ("grey18", [46, 46, 46]),
+    ("grey19", [48, 48, 48]),
+    ("grey2", [5, 5, 5]),
+    ("grey20", [51, 51, 51]),
+    ("grey21", [54, 54, 54]),
+    ("grey22", [56, 56, 56]),
+    ("grey23", [59, 59, 59]),
+    ("grey24", [61, 61, 61]),
+    ("grey25", [64, 64, 64]),
+    ("grey26", [66, 66, 66]),
+    ("grey27", [69, 69, 69]),
+    ("grey28", [71, 71, 71]),
+    ("grey29", [74, 74, 74]),
+    ("grey3", [8, 8, 8]),
+    ("grey30", [77, 77, 77]),
+    ("grey31", [79, 79, 79]),
+    ("grey32", [82, 82, 82]),
+    ("grey33", [84, 84, 84]),
+    ("grey34", [87, 87, 87]),
+    ("grey35", [89, 89, 89]),
+    ("grey36", [92, 92, 92]),
+    ("grey37", [94, 94, 94]),
+    ("grey38", [97, 97, 97]),
+    ("grey39", [99, 99, 99]),
+    ("grey4", [10, 10, 10]),
+    ("grey40", [102, 102, 102]),
+    ("grey41", [105, 105, 105]),
+    ("grey42", [107, 107, 107]),
+    ("grey43", [110, 110, 110]),
+    ("grey44", [112, 112, 112]),
+    ("grey45", [115, 115, 115]),
+    ("grey46", [117, 117, 117]),
+    ("grey47", [120, 120, 120]),
+    ("grey48", [122, 122, 122]),
+    ("grey49", [125, 125, 125]),
+    ("grey5", [13, 13, 13]),
+    ("grey50", [127, 127, 127]),
+    ("grey51", [130, 130, 130]),
+    ("grey52", [133, 133, 133]),
+    ("grey53", [135, 135, 135]),
+    ("grey54", [138, 138, 138]),
+    ("grey55", [140, 140, 140]),
+    ("grey56", [143, 143, 143]),
+    ("grey57", [145, 145, 145]),
+    ("grey58", [148, 148, 148]),
+    ("grey59", [150, 150, 150]),
+    ("grey6", [15, 15, 15]),
+    ("grey60", [153, 153, 153]),
+    ("grey61", [156, 156, 156]),
+    ("grey62", [158, 158, 158]),
+    ("grey63", [161, 161, 161]),
+    ("grey64", [163, 163, 163]),
+    ("grey65", [166, 166, 166]),
+    ("grey66", [168, 168, 168]),
+    ("grey67", [171, 171, 171]),
+    ("grey68", [173, 173, 173]),
+    ("grey69", [176, 176, 176]),
+    ("grey7", [18, 18, 18]),
+    ("grey70", [179, 179, 179]),
+    ("grey71", [181, 181, 181]),
+    ("grey72", [184, 184, 184]),
+    ("grey73", [186, 186, 186]),
+    ("grey74", [189, 189, 189]),
+    ("grey75", [191, 191, 191]),
+    ("grey76", [194, 194, 194]),
+    ("grey77", [196, 196, 196]),
+    ("grey78", [199, 199, 199]),
+    ("grey79", [201, 201, 201]),
+    ("grey8", [20, 20, 20]),
+    ("grey80", [204, 204, 204]),
+    ("grey81", [207, 207, 207]),
+    ("grey82", [209, 209, 209]),
+    ("grey83", [212, 212, 212]),
+    ("grey84", [214, 214, 214]),
+    ("grey85", [217, 217, 217]),
+    ("grey86", [219, 219, 219]),
+    ("grey87", [222, 222, 222]),
+    ("grey88", [224, 224, 224]),
+    ("grey89", [227, 227, 227]),
+    ("grey9", [23, 23, 23]),
+    ("grey90", [229, 229, 229]),
+    ("grey91", [232, 232, 232]),
+    ("grey92", [235, 235, 235]),
+    ("grey93", [237, 237, 237]),
+    ("grey94", [240, 240, 240]),
+    ("grey95", [242, 242, 242]),
+    ("grey96", [245, 245, 245]),
+    ("grey97", [247, 247, 247]),
+    ("grey98", [250, 250, 250]),
+    ("grey99", [252, 252, 252]),
+    ("honeydew", [240, 255, 240]),
+    ("honeydew1", [240, 255, 240]),
+    ("honeydew2", [224, 238, 224]),
+    ("honeydew3", [193, 205, 193]),
+    ("honeydew4", [131, 139, 131]),
+    ("hotpink", [255, 105, 180]),
+    ("hotpink1", [255, 110, 180]),
+    ("hotpink2", [238, 106, 167]),
+    ("hotpink3", [205, 96, 144]),
+    ("hotpink4", [139, 58, 98]),
+    ("indianred", [205, 92, 92]),
+    ("indianred1", [255, 106, 106]),
+    ("indianred2", [238, 99, 99]),
+    ("indianred3", [205, 85, 85]),
+    ("indianred4", [139, 58, 58]),
+    ("ivory", [255, 255, 240]),
+    ("ivory1", [255, 255, 240]),
+    ("ivory2", [238, 238, 224]),
+    ("ivory3", [205, 205, 193]),
+    ("ivory4", [139, 139, 131]),
+    ("khaki", [240, 230, 140]),
+    ("khaki1", [255, 246, 143]),
+    ("khaki2", [238, 230, 133]),
+    ("khaki3", [205, 198, 115]),
+    ("khaki4", [139, 134, 78]),
+    ("lavender", [230, 230, 250]),
+    ("lavenderblush", [255, 240, 245]),
+    ("lavenderblush1", [255, 240, 245]),
+    ("lavenderblush2", [238, 224, 229]),
+    ("lavenderblush3", [205, 193, 197]),
+    ("lavenderblush4", [139, 131, 134]),
+    ("lawngreen", [124, 252, 0]),
+    ("lemonchiffon", [255, 250, 205]),
+    ("lemonchiffon1", [255, 250, 205]),
+    ("lemonchiffon2", [238, 233, 191]),
+    ("lemonchiffon3", [205, 201, 165]),
+    ("lemonchiffon4", [139, 137, 112]),
+    ("lightblue", [173, 216, 230]),
+    ("lightblue1", [191, 239, 255]),
+    ("lightblue2", [178, 223, 238]),
+    ("lightblue3", [154, 192, 205]),
+    ("lightblue4", [104, 131, 139]),
+    ("lightcoral", [240, 128, 128]),
+    ("lightcyan", [224, 255, 255]),
+    ("lightcyan1", [224, 255, 255]),
+    ("lightcyan2", [209, 238, 238]),
+    ("lightcyan3", [180, 205, 205]),
+    ("lightcyan4", [122, 139, 139]),
+    ("lightgoldenrod", [238, 221, 130]),
+    ("lightgoldenrod1", [255, 236, 139]),
+    ("lightgoldenrod2", [238, 220, 130]),
+    ("lightgoldenrod3", [205, 190, 112]),
+    ("lightgoldenrod4", [139, 129, 76]),
+    ("lightgoldenrodyellow", [250, 250, 210]),
+    ("lightgray", [211, 211, 211]),
+    ("lightgreen", [144, 238, 144]),
+    ("lightgrey", [211, 211, 211]),
+    ("lightpink", [255, 182, 193]),
+    ("lightpink1", [255, 174, 185]),
+    ("lightpink2", [238, 162, 173]),
+    ("lightpink3", [205, 140, 149]),
+    ("lightpink4", [139, 95, 101]),
+    ("lightsalmon", [255, 160, 122]),
+    ("lightsalmon1", [255, 160, 122]),
+    ("lightsalmon2", [238, 149, 114]),
+    ("lightsalmon3", [205, 129, 98]),
+    ("lightsalmon4", [139, 87, 66]),
+    ("lightseagreen", [32, 178, 170]),
+    ("lightskyblue", [135, 206, 250]),
+    ("lightskyblue1", [176, 226, 255]),
+    ("lightskyblue2", [164, 211, 238]),
+    ("lightskyblue3", [141, 182, 205]),
+    ("lightskyblue4", [96, 123, 139]),
+    ("lightslateblue", [132, 112, 255]),
+    ("lightslategray", [119, 136, 153]),
+    ("lightslategrey", [119, 136, 153]),
+    ("lightsteelblue", [176, 196, 222]),
+    ("lightsteelblue1", [202, 225, 255]),
+    ("lightsteelblue2", [188, 210, 238]),
+    ("lightsteelblue3", [162, 181, 205]),
+    ("lightsteelblue4", [110, 123, 139]),
+    ("lightyellow", [255, 255, 224]),
+    ("lightyellow1", [255, 255, 224]),
+    ("lightyellow2", [238, 238, 209]),
+    ("lightyellow3", [205, 205, 180]),
+    ("lightyellow4", [139, 139, 122]),
+    ("limegreen", [50, 205, 50]),
+    ("linen", [250, 240, 230]),
+    ("magenta", [255, 0, 255]),
+    ("magenta1", [255, 0, 255]),
+    ("magenta2", [238, 0, 238]),
+    ("magenta3", [205, 0, 205]),
+    ("magenta4", [139, 0, 139]),
+    ("maroon", [176, 48, 96]),
+    ("maroon1", [255, 52, 179]),
+    ("maroon2", [238, 48, 167]),
+    ("maroon3", [205, 41, 144]),
+    ("maroon4", [139, 28, 98]),
+    ("mediumaquamarine", [102, 205, 170]),
+    ("mediumblue", [0, 0, 205]),
+    ("mediumorchid", [186, 85, 211]),
+    ("mediumorchid1", [224, 102, 255]),
+    ("mediumorchid2", [209, 95, 238]),
+    ("mediumorchid3", [180, 82, 205]),
+    ("mediumorchid4", [122, 55, 139]),
+    ("mediumpurple", [147, 112, 219]),
+    ("mediumpurple1", [171, 130, 255]),
+    ("mediumpurple2", [159, 121, 238]),
+    ("mediumpurple3", [137, 104, 205]),
+    ("mediumpurple4", [93, 71, 139]),
+    ("mediumseagreen", [60, 179, 113]),
+    ("mediumslateblue", [123, 104, 238]),
+    ("mediumspringgreen", [0, 250, 154]),
+    ("mediumturquoise", [72, 209, 204]),
+    ("mediumvioletred", [199, 21, 133]),
+    ("midnightblue", [25, 25, 112]),
+    ("mintcream", [245, 255, 250]),
+    ("mistyrose", [255, 228, 225]),
+    ("mistyrose1", [255, 228, 225]),
+    ("mistyrose2", [238, 213, 210]),
+    ("mistyrose3", [205, 183, 181]),
+    ("mistyrose4", [139, 125, 123]),
+    ("moccasin", [255, 228, 181]),
+    ("navajowhite", [255, 222, 173]),
+    ("navajowhite1", [255, 222, 173]),
+    ("navajowhite2", [238, 207, 161]),
+    ("navajowhite3", [205, 179, 139]),
+    ("navajowhite4", [139, 121, 94]),
+    ("navy", [0, 0, 128]),
+    ("navyblue", [0, 0, 128]),
+    ("oldlace", [253, 245, 230]),
+    ("olivedrab", [107, 142, 35]),
+    ("olivedrab1", [192, 255, 62]),
+    ("olivedrab2", [179, 238, 58]),
+    ("olivedrab3", [154, 205, 50]),
+    ("olivedrab4", [105, 139, 34]),
+    ("orange", [255, 165, 0]),
+    ("orange1", [255, 165, 0]),
+    ("orange2", [238, 154, 0]),
+    ("orange3", [205, 133, 0]),
+    ("orange4", [139, 90, 0]),
+    ("orangered", [255, 69, 0]),
+    ("orangered1", [255, 69, 0]),
+    ("orangered2", [238, 64, 0]),
+    ("orangered3", [205, 55, 0]),
+    ("orangered4", [139, 37, 0]),
+    ("orchid", [218, 112, 214]),
+    ("orchid1", [255, 131, 250]),
+    ("orchid2", [238, 122, 233]),
+    ("orchid3", [205, 105, 201]),
+    ("orchid4", [139, 71, 137]),
+    ("palegoldenrod", [238, 232, 170]),
+    ("palegreen", [152, 251, 152]),
+    ("palegreen1", [154, 255, 154]),
+    ("palegreen2", [144, 238, 144]),
+    ("palegreen3", [124, 205, 124]),
+    ("palegreen4", [84, 139, 84]),
+    ("paleturquoise", [175, 238, 238]),
+    ("paleturquoise1", [187, 255, 255]),
+    ("paleturquoise2", [174, 238, 238]),
+    ("paleturquoise3", [150, 205, 205]),
+    ("paleturquoise4", [102, 139, 139]),
+    ("palevioletred", [219, 112, 147]),
+    ("palevioletred1", [255, 130, 171]),
+    ("palevioletred2", [238, 121, 159]),
+    ("palevioletred3", [205, 104, 137]),
+    ("palevioletred4", [139, 71, 93]),
+    ("papayawhip", [255, 239, 213]),
+    ("peachpuff", [255, 218, 185]),
+    ("peachpuff1", [255, 218, 185]),
+    ("peachpuff2", [238, 203, 173]),
+    ("peachpuff3", [205, 175, 149]),
+    ("peachpuff4", [139, 119, 101]),
+    ("peru", [205, 133, 63]),
+    ("pink", [255, 192, 203]),
+    ("pink1", [255, 181, 197]),
+    ("pink2", [238, 169, 184]),
+    ("pink3", [205, 145, 158]),
+    ("pink4", [139, 99, 108]),
+    ("plum", [221, 160, 221]),
+    ("plum1", [255, 187, 255]),
+    ("plum2", [238, 174, 238]),
+    ("plum3", [205, 150, 205]),
+    ("plum4", [139, 102, 139]),
+    ("powderblue", [176, 224, 230]),
+    ("purple", [160, 32, 240]),
+    ("purple1", [155, 48, 255]),
+    ("purple2", [145, 44, 238]),
+    ("purple3", [125, 38, 205]),
+    ("purple4", [85, 26, 139]),
+    ("red", [255, 0, 0]),
+    ("red1", [255, 0, 0]),
+    ("red2", [238, 0, 0]),
+    ("red3", [205, 0, 0]),
+    ("red4", [139, 0, 0]),
+    ("rosybrown", [188, 143, 143]),
+    ("rosybrown1", [255, 193, 193]),
+    ("rosybrown2", [238, 180, 180]),
+    ("rosybrown3", [205, 155, 155]),
+    ("rosybrown4", [139, 105, 105]),
+    ("royalblue", [65, 105, 225]),
+    ("royalblue1", [72, 118, 255]),
+    ("royalblue2", [67, 110, 238]),
+    ("royalblue3", [58, 95, 205]),
+    ("royalblue4", [39, 64, 139]),
+    ("saddlebrown", [139, 69, 19]),
+    ("salmon", [250, 128, 114]),
+    ("salmon1", [255, 140, 105]),
+    ("salmon2", [238, 130, 98]),
+    ("salmon3", [205, 112, 84]),
+    ("salmon4", [139, 76, 57]),
+    ("sandybrown", [244, 164, 96]),
+    ("seagreen", [46, 139, 87]),
+    ("seagreen1", [84, 255, 159]),
+    ("seagreen2", [78, 238, 148]),
+    ("seagreen3", [67, 205, 128]),
+    ("seagreen4", [46, 139, 87]),
+    ("seashell", [255, 245, 238]),
+    ("seashell1", [255, 245, 238]),
+    ("seashell2", [238, 229, 222]),
+    ("seashell3", [205, 197, 191]),
+    ("seashell4", [139, 134, 130]),
+    ("sienna", [160, 82, 45]),
+    ("sienna1", [255, 130, 71]),
+    ("sienna2", [238, 121, 66]),
+    ("sienna3", [205, 104, 57]),
+    ("sienna4", [139, 71, 38]),
+    ("skyblue", [135, 206, 235]),
+    ("skyblue1", [135, 206, 255]),
+    ("skyblue2", [126, 192, 238]),
+    ("skyblue3", [108, 166, 205]),
+    ("skyblue4", [74, 112, 139]),
+    ("slateblue", [106, 90, 205]),
+    ("slateblue1", [131, 111, 255]),
+    ("slateblue2", [122, 103, 238]),
+    ("slateblue3", [105, 89, 205]),
+    ("slateblue4", [71, 60, 139]),
+    ("slategray", [112, 128, 144]),
+    ("slategray1", [198, 226, 255]),
+    ("slategray2", [185, 211, 238]),
+    ("slategray3", [159, 182, 205]),
+    ("slategray4", [108, 123, 139]),
+    ("slategrey", [112, 128, 144]),
+    ("snow", [255, 250, 250]),
+    ("snow1", [255, 250, 250]),
+    ("snow2", [238, 233, 233]),
+    ("snow3", [205, 201, 201]),
+    ("snow4", [139, 137, 137]),
+    ("springgreen", [0, 255, 127]),
+    ("springgreen1", [0, 255, 127]),
+    ("springgreen2", [0, 238, 118]),
+    ("springgreen3", [0, 205, 102]),
+    ("springgreen4", [0, 139, 69]),
+    ("steelblue", [70, 130, 180]),
+    ("steelblue1", [99, 184, 255]),
+    ("steelblue2", [92, 172, 238]),
+    ("steelblue3", [79, 148, 205]),
+    ("steelblue4", [54, 100, 139]),
+    ("tan", [210, 180, 140]),
+    ("tan1", [255, 165, 79]),
+    ("tan2", [238, 154, 73]),
+    ("tan3", [205, 133, 63]),
+    ("tan4", [139, 90, 43]),
+    ("thistle", [216, 191, 216]),
+    ("thistle1", [255, 225, 255]),
+    ("thistle2", [238, 210, 238]),
+    ("thistle3", [205, 181, 205]),
+    ("thistle4", [139, 123, 139]),
+    ("tomato", [255, 99, 71]),
+    ("tomato1", [255, 99, 71]),
+    ("tomato2", [238, 92, 66]),
+    ("tomato3", [205, 79, 57]),
+    ("tomato4", [139, 54, 38]),
+    ("turquoise", [64, 224, 208]),
+    ("turquoise1", [0, 245, 255]),
+    ("turquoise2", [0, 229, 238]),
+    ("turquoise3", [0, 197, 205]),
+    ("turquoise4", [0, 134, 139]),
+    ("violet", [238, 130, 238]),
+    ("violetred", [208, 32, 144]),
+    ("violetred1", [255, 62, 150]),
+    ("violetred2", [238, 58, 140]),
+    ("violetred3", [205, 50, 120]),
+    ("violetred4", [139, 34, 82]),
+    ("wheat", [245, 222, 179]),
+    ("wheat1", [255, 231, 186]),
+    ("wheat2", [238, 216, 174]),
+    ("wheat3", [205, 186, 150]),
+    ("wheat4", [139, 126, 102]),
+    ("white", [255, 255, 255]),
+    ("whitesmoke", [245, 245, 245]),
+    ("yellow", [255, 255, 0]),
+    ("yellow1", [255, 255, 0]),
+    ("yellow2", [238, 238, 0]),
+    ("yellow3", [205, 205, 0]),
+    ("yellow4", [139, 139, 0]),
+    ("yellowgreen", [154, 205, 50]),
+];
+
+/// The xkcd color survey results, <https://xkcd.com/color/rgb.txt> released under CC0.
+/// Names keep their spaces since some only differ by them, like `egg shell` and `eggshell`,
+/// sorted by their lookup key
+#[rustfmt::skip]
+static XKCD_COLORS: [(&str, [u8; 3]); 949] = [
+    ("acid green", [143, 254, 9]),
+    ("adobe", [189, 108, 72]),
+    ("algae", [84, 172, 104]),
+    ("algae green", [33, 195, 111]),
+    ("almost black", [7, 13, 13]),
+    ("amber", [254, 179, 8]),
+    ("amethyst", [155, 95, 192]),
+    ("apple", [110, 203, 60]),
+    ("apple green", [118, 205, 38]),
+    ("apricot", [255, 177, 109]),
+    ("aqua", [19, 234, 201]),
+    ("aqua blue", [2, 216, 233]),
+    ("aqua green", [18, 225, 147]),
+    ("aqua marine", [46, 232, 187]),
+    ("aquamarine", [4, 216, 178]),
+    ("army green", [75, 93, 22]),
+    ("asparagus", [119, 171, 86]),
+    ("aubergine", [61, 7, 52]),
+    ("auburn", [154, 48, 1]),
+    ("avocado", [144, 177, 52]),
+    ("avocado green", [135, 169, 34]),
+    ("azul", [29, 93, 236]),
+    ("azure", [6, 154, 243]),
+    ("baby blue", [162, 207, 254]),
+    ("baby green", [140, 255, 158]),
+    ("baby pink", [255, 183, 206]),
+    ("baby poo", [171, 144, 4]),
+    ("baby poop", [147, 124, 0]),
+    ("baby poop green", [143, 152, 5]),
+    ("baby puke green", [182, 196, 6]),
+    ("baby purple", [202, 155, 247]),
+    ("baby shit brown", [173, 144, 13]),
+    ("baby shit green", [136, 151, 23]),
+    ("banana", [255, 255, 126]),
+    ("banana yellow", [250, 254, 75]),
+    ("barbie pink", [254, 70, 165]),
+    ("barf green", [148, 172, 2]),
+    ("barney", [172, 29, 184]),
+    ("barney purple", [160, 4, 152]),
+    ("battleship grey", [107, 124, 133]),
+    ("beige", [230, 218, 166]),
+    ("berry", [153, 15, 75]),
+    ("bile", [181, 195, 6]),
+    ("black", [0, 0, 0]),
+    ("bland", [175, 168, 139]),
+    ("blood", [119, 0, 1]),
+    ("blood orange", [254, 75, 3]),
+    ("blood red", [152, 0, 2]),
+    ("blue", [3, 67, 223]),
+    ("blue/green", [15, 155, 142]),
+    ("blue/grey", [117, 141, 163]),
+    ("blue/purple", [90, 6, 239]),
+    ("blueberry", [70, 65, 150]),
+    ("blue blue", [34, 66, 199]),
+    ("blue green", [19, 126, 109]),
+    ("bluegreen", [1, 122, 121]),
+    ("blue grey", [96, 124, 142]),
+    ("bluegrey", [133, 163, 178]),
+    ("blue purple", [87, 41, 206]),
+    ("blue violet", [93, 6, 233]),
+    ("blue with a hint of purple", [83, 60, 198]),
+    ("bluey green", [43, 177, 121]),
+    ("bluey grey", [137, 160, 176]),
+    ("bluey purple", [98, 65, 199]),
+    ("bluish", [41, 118, 187]),
+    ("bluish green", [16, 166, 116]),
+    ("bluish grey", [116, 139, 151]),
+    ("bluish purple", [112, 59, 231]),
+    ("blurple", [85, 57, 204]),
+    ("blush", [242, 158, 142]),
+    ("blush pink", [254, 130, 140]),
+    ("booger", [155, 181, 60]),
+    ("booger green", [150, 180, 3]),
+    ("bordeaux", [123, 0, 44]),
+    ("boring green", [99, 179, 101]),
+    ("bottle green", [4, 74, 5]),
+    ("brick", [160, 54, 35]),
+    ("brick orange", [193, 74, 9]),
+    ("brick red", [143, 20, 2]),
+    ("bright aqua", [11, 249, 234]),
+    ("bright blue", [1, 101, 252]),
+    ("bright cyan", [65, 253, 254]),
+    ("bright green", [1, 255, 7]),
+    ("bright lavender", [199, 96, 255]),
+    ("bright light blue", [38, 247, 253]),
+    ("bright light green", [45, 254, 84]),
+    ("bright lilac", [201, 94, 251]),
+    ("bright lime", [135, 253, 5]),
+    ("bright lime green", [101, 254, 8]),
+    ("bright magenta", [255, 8, 232]),
+    ("bright olive", [156, 187, 4]),
+    ("bright orange", [255, 91, 0]),
+    ("bright pink", [254, 1, 177]),
+    ("bright purple", [190, 3, 253]),
+    ("bright red", [255, 0, 13]),
+    ("bright sea green", [5, 255, 166]),
+    ("bright sky blue", [2, 204, 254]),
+    ("bright teal", [1, 249, 198]),
+    ("bright turquoise", [15, 254, 249]),
+    ("bright violet", [173, 10, 253]),
+    ("bright yellow", [255, 253, 1]),
+    ("bright yellow green", [157, 255, 0]),
+    ("british racing green", [5, 72, 13]),
+    ("bronze", [168, 121, 0]),
+    ("brown", [101, 55, 0]),
+    ("brown green", [112, 108, 17]),
+    ("brown grey", [141, 132, 104]),
+    ("brownish", [156, 109, 87]),
+    ("brownish green", [106, 110, 9]),
+    ("brownish grey", [134, 119, 95]),
+    ("brownish orange", [203, 119, 35]),
+    ("brownish pink", [194, 126, 121]),
+    ("brownish purple", [118, 66, 78]),
+    ("brownish red", [158, 54, 35]),
+    ("brownish yellow", [201, 176, 3]),
+    ("brown orange", [185, 105, 2]),
+    ("brown red", [146, 43, 5]),
+    ("brown yellow", [178, 151, 5]),
+    ("browny green", [111, 108, 10]),
+    ("browny orange", [202, 107, 2]),
+    ("bruise", [126, 64, 113]),
+    ("bubblegum", [255, 108, 181]),
+    ("bubble gum pink", [255, 105, 175]),
+    ("bubblegum pink", [254, 131, 204]),
+    ("buff", [254, 246, 158]),
+    ("burgundy", [97, 0, 35]),
+    ("burnt orange", [192, 78, 1]),
+    ("burnt red", [159, 35, 5]),
+    ("burnt siena", [183, 82, 3]),
+    ("burnt sienna", [176, 78, 15]),
+    ("burnt umber", [160, 69, 14]),
+    ("burnt yellow", [213, 171, 9]),
+    ("burple", [104, 50, 227]),
+    ("butter", [255, 255, 129]),
+    ("butterscotch", [253, 177, 71]),
+    ("butter yellow", [255, 253, 116]),
+    ("cadet blue", [78, 116, 150]),
+    ("camel", [198, 159, 89]),
+    ("camo", [127, 143, 78]),
+    ("camo green", [82, 101, 37]),
+    ("camouflage green", [75, 97, 19]),
+    ("canary", [253, 255, 99]),
+    ("canary yellow", [255, 254, 64]),
+    ("candy pink", [255, 99, 233]),
+    ("caramel", [175, 111, 9]),
+    ("carmine", [157, 2, 22]),
+    ("carnation", [253, 121, 143]),
+    ("carnation pink", [255, 127, 167]),
+    ("carolina blue", [138, 184, 254]),
+    ("celadon", [190, 253, 183]),
+    ("celery", [193, 253, 149]),
+    ("cement", [165, 163, 145]),
+    ("cerise", [222, 12, 98]),
+    ("cerulean", [4, 133, 209]),
+    ("cerulean blue", [5, 110, 238]),
+    ("charcoal", [52, 56, 55]),
+    ("charcoal grey", [60, 65, 66]),
+    ("chartreuse", [193, 248, 10]),
+    ("cherry", [207, 2, 52]),
+    ("cherry red", [247, 2, 42]),
+    ("chestnut", [116, 40, 2]),
+    ("chocolate", [61, 28, 2]),
+    ("chocolate brown", [65, 25, 0]),
+    ("cinnamon", [172, 79, 6]),
+    ("claret", [104, 0, 24]),
+    ("clay", [182, 106, 80]),
+    ("clay brown", [178, 113, 61]),
+    ("clear blue", [36, 122, 253]),
+    ("cloudy blue", [172, 194, 217]),
+    ("cobalt", [30, 72, 143]),
+    ("cobalt blue", [3, 10, 167]),
+    ("cocoa", [135, 95, 66]),
+    ("coffee", [166, 129, 76]),
+    ("cool blue", [73, 132, 184]),
+    ("cool green", [51, 184, 100]),
+    ("cool grey", [149, 163, 166]),
+    ("copper", [182, 99, 37]),
+    ("coral", [252, 90, 80]),
+    ("coral pink", [255, 97, 99]),
+    ("cornflower", [106, 121, 247]),
+    ("cornflower blue", [81, 112, 215]),
+    ("cranberry", [158, 0, 58]),
+    ("cream", [255, 255, 194]),
+    ("creme", [255, 255, 182]),
+    ("crimson", [140, 0, 15]),
+    ("custard", [255, 253, 120]),
+    ("cyan", [0, 255, 255]),
+    ("dandelion", [254, 223, 8]),
+    ("dark", [27, 36, 49]),
+    ("dark aqua", [5, 105, 107]),
+    ("dark aquamarine", [1, 115, 113]),
+    ("dark beige", [172, 147, 98]),
+    ("dark blue", [0, 3, 91]),
+    ("darkblue", [3, 7, 100]),
+    ("dark blue green", [0, 82, 73]),
+    ("dark blue grey", [31, 59, 77]),
+    ("dark brown", [52, 28, 2]),
+    ("dark coral", [207, 82, 78]),
+    ("dark cream", [255, 243, 154]),
+    ("dark cyan", [10, 136, 138]),
+    ("dark forest green", [0, 45, 4]),
+    ("dark fuchsia", [157, 7, 89]),
+    ("dark gold", [181, 148, 16]),
+    ("dark grass green", [56, 128, 4]),
+    ("dark green", [3, 53, 0]),
+    ("darkgreen", [5, 73, 7]),
+    ("dark green blue", [31, 99, 87]),
+    ("dark grey", [54, 55, 55]),
+    ("dark grey blue", [41, 70, 91]),
+    ("dark hot pink", [217, 1, 102]),
+    ("dark indigo", [31, 9, 84]),
+    ("darkish blue", [1, 65, 130]),
+    ("darkish green", [40, 124, 55]),
+    ("darkish pink", [218, 70, 125]),
+    ("darkish purple", [117, 25, 115]),
+    ("darkish red", [169, 3, 8]),
+    ("dark khaki", [155, 143, 85]),
+    ("dark lavender", [133, 103, 152]),
+    ("dark lilac", [156, 109, 165]),
+    ("dark lime", [132, 183, 1]),
+    ("dark lime green", [126, 189, 1]),
+    ("dark magenta", [150, 0, 86]),
+    ("dark maroon", [60, 0, 8]),
+    ("dark mauve", [135, 76, 98]),
+    ("dark mint", [72, 192, 114]),
+    ("dark mint green", [32, 192, 115]),
+    ("dark mustard", [168, 137, 5]),
+    ("dark navy", [0, 4, 53]),
+    ("dark navy blue", [0, 2, 46]),
+    ("dark olive", [55, 62, 2]),
+    ("dark olive green", [60, 77, 3]),
+    ("dark orange", [198, 81, 2]),
+    ("dark pastel green", [86, 174, 87]),
+    ("dark peach", [222, 126, 93]),
+    ("dark periwinkle", [102, 95, 209]),
+    ("dark pink", [203, 65, 107]),
+    ("dark plum", [63, 1, 44]),
+    ("dark purple", [53, 6, 62]),
+    ("dark red", [132, 0, 0]),
+    ("dark rose", [181, 72, 93]),
+    ("dark royal blue", [2, 6, 111]),
+    ("dark sage", [89, 133, 86]),
+    ("dark salmon", [200, 90, 83]),
+    ("dark sand", [168, 143, 89]),
+    ("dark seafoam", [31, 181, 122]),
+    ("dark seafoam green", [62, 175, 118]),
+    ("dark sea green", [17, 135, 93]),
+    ("dark sky blue", [68, 142, 228]),
+    ("dark slate blue", [33, 71, 97]),
+    ("dark tan", [175, 136, 74]),
+    ("dark taupe", [127, 104, 78]),
+    ("dark teal", [1, 77, 78]),
+    ("dark turquoise", [4, 92, 90]),
+    ("dark violet", [52, 1, 63]),
+    ("dark yellow", [213, 182, 10]),
+    ("dark yellow green", [114, 143, 2]),
+    ("deep aqua", [8, 120, 127]),
+    ("deep blue", [4, 2, 115]),
+    ("deep brown", [65, 2, 0]),
+    ("deep green", [2, 89, 15]),
+    ("deep lavender", [141, 94, 183]),
+    ("deep lilac", [150, 110, 189]),
+    ("deep magenta", [160, 2, 92]),
+    ("deep orange", [220, 77, 1]),
+    ("deep pink", [203, 1, 98]),
+    ("deep purple", [54, 1, 63]),
+    ("deep red", [154, 2, 0]),
+    ("deep rose", [199, 71, 103]),
+    ("deep sea blue", [1, 84, 130]),
+    ("deep sky blue", [13, 117, 248]),
+    ("deep teal", [0, 85, 90]),
+    ("deep turquoise", [1, 115, 116]),
+    ("deep violet", [73, 6, 72]),
+    ("denim", [59, 99, 140]),
+    ("denim blue", [59, 91, 146]),
+    ("desert", [204, 173, 96]),
+    ("diarrhea", [159, 131, 3]),
+    ("dirt", [138, 110, 69]),
+    ("dirt brown", [131, 101, 57]),
+    ("dirty blue", [63, 130, 157]),
+    ("dirty green", [102, 126, 44]),
+    ("dirty orange", [200, 118, 6]),
+    ("dirty pink", [202, 123, 128]),
+    ("dirty purple", [115, 74, 101]),
+    ("dirty yellow", [205, 197, 10]),
+    ("dodger blue", [62, 130, 252]),
+    ("drab", [130, 131, 68]),
+    ("drab green", [116, 149, 81]),
+    ("dried blood", [75, 1, 1]),
+    ("duck egg blue", [195, 251, 244]),
+    ("dull blue", [73, 117, 156]),
+    ("dull brown", [135, 110, 75]),
+    ("dull green", [116, 166, 98]),
+    ("dull orange", [216, 134, 59]),
+    ("dull pink", [213, 134, 157]),
+    ("dull purple", [132, 89, 126]),
+    ("dull red", [187, 63, 63]),
+    ("dull teal", [95, 158, 143]),
+    ("dull yellow", [238, 220, 91]),
+    ("dusk", [78, 84, 129]),
+    ("dusk blue", [38, 83, 141]),
+    ("dusky blue", [71, 95, 148]),
+    ("dusky pink", [204, 122, 139]),
+    ("dusky purple", [137, 91, 123]),
+    ("dusky rose", [186, 104, 115]),
+    ("dust", [178, 153, 110]),
+    ("dusty blue", [90, 134, 173]),
+    ("dusty green", [118, 169, 115]),
+    ("dusty lavender", [172, 134, 168]),
+    ("dusty orange", [240, 131, 58]),
+    ("dusty pink", [213, 138, 148]),
+    ("dusty purple", [130, 95, 135]),
+    ("dusty red", [185, 72, 78]),
+    ("dusty rose", [192, 115, 122]),
+    ("dusty teal", [76, 144, 133]),
+    ("earth", [162, 101, 62]),
+    ("easter green", [140, 253, 126]),
+    ("easter purple", [192, 113, 254]),
+    ("ecru", [254, 255, 202]),
+    ("eggplant", [56, 8, 53]),
+    ("eggplant purple", [67, 5, 65]),
+    ("egg shell", [255, 252, 196]),
+    ("eggshell", [255, 255, 212]),
+    ("eggshell blue", [196, 255, 247]),
+    ("electric blue", [6, 82, 255]),
+    ("electric green", [33, 252, 13]),
+    ("electric lime", [168, 255, 4]),
+    ("electric pink", [255, 4, 144]),
+    ("electric purple", [170, 35, 255]),
+    ("emerald", [1, 160, 73]),
+    ("emerald green", [2, 143, 30]),
+    ("evergreen", [5, 71, 42]),
+    ("faded blue", [101, 140, 187]),
+    ("faded green", [123, 178, 116]),
+    ("faded orange", [240, 148, 77]),
+    ("faded pink", [222, 157, 172]),
+    ("faded purple", [145, 110, 153]),
+    ("faded red", [211, 73, 78]),
+    ("faded yellow", [254, 255, 127]),
+    ("fawn", [207, 175, 123]),
+    ("fern", [99, 169, 80]),
+    ("fern green", [84, 141, 68]),
+    ("fire engine red", [254, 0, 2]),
+    ("flat blue", [60, 115, 168]),
+    ("flat green", [105, 157, 76]),
+    ("fluorescent green", [8, 255, 8]),
+    ("fluro green", [10, 255, 2]),
+    ("foam green", [144, 253, 169]),
+    ("forest", [11, 85, 9]),
+    ("forest green", [6, 71, 12]),
+    ("forrest green", [21, 68, 6]),
+    ("french blue", [67, 107, 173]),
+    ("fresh green", [105, 216, 79]),
+    ("frog green", [88, 188, 8]),
+    ("fuchsia", [237, 13, 217]),
+    ("gold", [219, 180, 12]),
+    ("golden", [245, 191, 3]),
+    ("golden brown", [178, 122, 1]),
+    ("golden rod", [249, 188, 8]),
+    ("goldenrod", [250, 194, 5]),
+    ("golden yellow", [254, 198, 21]),
+    ("grape", [108, 52, 97]),
+    ("grapefruit", [253, 89, 86]),
+    ("grape purple", [93, 20, 81]),
+    ("grass", [92, 172, 45]),
+    ("grass green", [63, 155, 11]),
+    ("grassy green", [65, 156, 3]),
+    ("green", [21, 176, 26]),
+    ("green/blue", [1, 192, 141]),
+    ("green/yellow", [181, 206, 8]),
+    ("green apple", [94, 220, 31]),
+    ("green blue", [6, 180, 139]),
+    ("greenblue", [35, 196, 139]),
+    ("green brown", [84, 78, 3]),
+    ("green grey", [119, 146, 111]),
+    ("greenish", [64, 163, 104]),
+    ("greenish beige", [201, 209, 121]),
+    ("greenish blue", [11, 139, 135]),
+    ("greenish brown", [105, 97, 18]),
+    ("greenish cyan", [42, 254, 183]),
+    ("greenish grey", [150, 174, 141]),
+    ("greenish tan", [188, 203, 122]),
+    ("greenish teal", [50, 191, 132]),
+    ("greenish turquoise", [0, 251, 176]),
+    ("greenish yellow", [205, 253, 2]),
+    ("green teal", [12, 181, 119]),
+    ("greeny blue", [66, 179, 149]),
+    ("greeny brown", [105, 96, 6]),
+    ("green yellow", [201, 255, 39]),
+    ("greeny grey", [126, 160, 122]),
+    ("greeny yellow", [198, 248, 8]),
+    ("grey", [146, 149, 145]),
+    ("grey/blue", [100, 125, 142]),
+    ("grey/green", [134, 161, 125]),
+    ("grey blue", [107, 139, 164]),
+    ("greyblue", [119, 161, 181]),
+    ("grey brown", [127, 112, 83]),
+    ("grey green", [120, 155, 115]),
+    ("greyish", [168, 164, 149]),
+    ("greyish blue", [94, 129, 157]),
+    ("greyish brown", [122, 106, 79]),
+    ("greyish green", [130, 166, 125]),
+    ("greyish pink", [200, 141, 148]),
+    ("greyish purple", [136, 113, 145]),
+    ("greyish teal", [113, 159, 145]),
+    ("grey pink", [195, 144, 155]),
+    ("grey purple", [130, 109, 140]),
+    ("grey teal", [94, 155, 138]),
+    ("gross green", [160, 191, 22]),
+    ("gunmetal", [83, 98, 103]),
+    ("hazel", [142, 118, 24]),
+    ("heather", [164, 132, 172]),
+    ("heliotrope", [217, 79, 245]),
+    ("highlighter green", [27, 252, 6]),
+    ("hospital green", [155, 229, 170]),
+    ("hot green", [37, 255, 41]),
+    ("hot magenta", [245, 4, 201]),
+    ("hot pink", [255, 2, 141]),
+    ("hot purple", [203, 0, 245]),
+    ("hunter green", [11, 64, 8]),
+    ("ice", [214, 255, 250]),
+    ("ice blue", [215, 255, 254]),
+    ("icky green", [143, 174, 34]),
+    ("indian red", [133, 14, 4]),
+    ("indigo", [56, 2, 130]),
+    ("indigo blue", [58, 24, 177]),
+    ("iris", [98, 88, 196]),
+    ("irish green", [1, 149, 41]),
+    ("ivory", [255, 255, 203]),
+    ("jade", [31, 167, 116]),
+    ("jade green", [43, 175, 106]),
+    ("jungle green", [4, 130, 67]),
+    ("kelley green", [0, 147, 55]),
+    ("kelly green", [2, 171, 46]),
+    ("kermit green", [92, 178, 0]),
+    ("key lime", [174, 255, 110]),
+    ("khaki", [170, 166, 98]),
+    ("khaki green", [114, 134, 57]),
+    ("kiwi", [156, 239, 67]),
+    ("kiwi green", [142, 229, 63]),
+    ("lavender", [199, 159, 239]),
+    ("lavender blue", [139, 136, 248]),
+    ("lavender pink", [221, 133, 215]),
+    ("lawn green", [77, 164, 9]),
+    ("leaf", [113, 170, 52]),
+    ("leaf green", [92, 169, 4]),
+    ("leafy green", [81, 183, 59]),
+    ("leather", [172, 116, 52]),
+    ("lemon", [253, 255, 82]),
+    ("lemon green", [173, 248, 2]),
+    ("lemon lime", [191, 254, 40]),
+    ("lemon yellow", [253, 255, 56]),
+    ("lichen", [143, 182, 123]),
+    ("light aqua", [140, 255, 219]),
+    ("light aquamarine", [123, 253, 199]),
+    ("light beige", [255, 254, 182]),
+    ("light blue", [149, 208, 252]),
+    ("lightblue", [123, 200, 246]),
+    ("light blue green", [126, 251, 179]),
+    ("light blue grey", [183, 201, 226]),
+    ("light bluish green", [118, 253, 168]),
+    ("light bright green", [83, 254, 92]),
+    ("light brown", [173, 129, 80]),
+    ("light burgundy", [168, 65, 91]),
+    ("light cyan", [172, 255, 252]),
+    ("light eggplant", [137, 69, 133]),
+    ("lighter green", [117, 253, 99]),
+    ("lighter purple", [165, 90, 244]),
+    ("light forest green", [79, 145, 83]),
+    ("light gold", [253, 220, 92]),
+    ("light grass green", [154, 247, 100]),
+    ("light green", [150, 249, 123]),
+    ("lightgreen", [118, 255, 123]),
+    ("light green blue", [86, 252, 162]),
+    ("light greenish blue", [99, 247, 180]),
+    ("light grey", [216, 220, 214]),
+    ("light grey blue", [157, 188, 212]),
+    ("light grey green", [183, 225, 161]),
+    ("light indigo", [109, 90, 207]),
+    ("lightish blue", [61, 122, 253]),
+    ("lightish green", [97, 225, 96]),
+    ("lightish purple", [165, 82, 230]),
+    ("lightish red", [254, 47, 74]),
+    ("light khaki", [230, 242, 162]),
+    ("light lavendar", [239, 192, 254]),
+    ("light lavender", [223, 197, 254]),
+    ("light light blue", [202, 255, 251]),
+    ("light light green", [200, 255, 176]),
+    ("light lilac", [237, 200, 255]),
+    ("light lime", [174, 253, 108]),
+    ("light lime green", [185, 255, 102]),
+    ("light magenta", [250, 95, 247]),
+    ("light maroon", [162, 72, 87]),
+    ("light mauve", [194, 146, 161]),
+    ("light mint", [182, 255, 187]),
+    ("light mint green", [166, 251, 178]),
+    ("light moss green", [166, 200, 117]),
+    ("light mustard", [247, 213, 96]),
+    ("light navy", [21, 80, 132]),
+    ("light navy blue", [46, 90, 136]),
+    ("light neon green", [78, 253, 84]),
+    ("light olive", [172, 191, 105]),
+    ("light olive green", [164, 190, 92]),
+    ("light orange", [253, 170, 72]),
+    ("light pastel green", [178, 251, 165]),
+    ("light peach", [255, 216, 177]),
+    ("light pea green", [196, 254, 130]),
+    ("light periwinkle", [193, 198, 252]),
+    ("light pink", [255, 209, 223]),
+    ("light plum", [157, 87, 131]),
+    ("light purple", [191, 119, 246]),
+    ("light red", [255, 71, 76]),
+    ("light rose", [255, 197, 203]),
+    ("light royal blue", [58, 46, 254]),
+    ("light sage", [188, 236, 172]),
+    ("light salmon", [254, 169, 147]),
+    ("light seafoam", [160, 254, 191]),
+    ("light seafoam green", [167, 255, 181]),
+    ("light sea green", [152, 246, 176]),
+    ("light sky blue", [198, 252, 255]),
+    ("light tan", [251, 238, 172]),
+    ("light teal", [144, 228, 193]),
+    ("light turquoise", [126, 244, 204]),
+    ("light urple", [179, 111, 246]),
+    ("light violet", [214, 180, 252]),
+    ("light yellow", [255, 254, 122]),
+    ("light yellow green", [204, 253, 127]),
+    ("light yellowish green", [194, 255, 137]),
+    ("lilac", [206, 162, 253]),
+    ("liliac", [196, 142, 253]),
+    ("lime", [170, 255, 50]),
+    ("lime green", [137, 254, 5]),
+    ("lime yellow", [208, 254, 29]),
+    ("lipstick", [213, 23, 78]),
+    ("lipstick red", [192, 2, 47]),
+    ("macaroni and cheese", [239, 180, 53]),
+    ("magenta", [194, 0, 120]),
+    ("mahogany", [74, 1, 0]),
+    ("maize", [244, 208, 84]),
+    ("mango", [255, 166, 43]),
+    ("manilla", [255, 250, 134]),
+    ("marigold", [252, 192, 6]),
+    ("marine", [4, 46, 96]),
+    ("marine blue", [1, 56, 106]),
+    ("maroon", [101, 0, 33]),
+    ("mauve", [174, 113, 129]),
+    ("medium blue", [44, 111, 187]),
+    ("medium brown", [127, 81, 18]),
+    ("medium green", [57, 173, 72]),
+    ("medium grey", [125, 127, 124]),
+    ("medium pink", [243, 97, 150]),
+    ("medium purple", [158, 67, 162]),
+    ("melon", [255, 120, 85]),
+    ("merlot", [115, 0, 57]),
+    ("metallic blue", [79, 115, 142]),
+    ("mid blue", [39, 106, 179]),
+    ("mid green", [80, 167, 71]),
+    ("midnight", [3, 1, 45]),
+    ("midnight blue", [2, 0, 53]),
+    ("midnight purple", [40, 1, 55]),
+    ("military green", [102, 124, 62]),
+    ("milk chocolate", [127, 78, 30]),
+    ("mint", [159, 254, 176]),
+    ("mint green", [143, 255, 159]),
+    ("minty green", [11, 247, 125]),
+    ("mocha", [157, 118, 81]),
+    ("moss", [118, 153, 88]),
+    ("moss green", [101, 139, 56]),
+    ("mossy green", [99, 139, 39]),
+    ("mud", [115, 92, 18]),
+    ("mud brown", [96, 70, 15]),
+    ("muddy brown", [136, 104, 6]),
+    ("muddy green", [101, 116, 50]),
+    ("muddy yellow", [191, 172, 5]),
+    ("mud green", [96, 102, 2]),
+    ("mulberry", [146, 10, 78]),
+    ("murky green", [108, 122, 14]),
+    ("mushroom", [186, 158, 136]),
+    ("mustard", [206, 179, 1]),
+    ("mustard brown", [172, 126, 4]),
+    ("mustard green", [168, 181, 4]),
+    ("mustard yellow", [210, 189, 10]),
+    ("muted blue", [59, 113, 159]),
+    ("muted green", [95, 160, 82]),
+    ("muted pink", [209, 118, 143]),
+    ("muted purple", [128, 91, 135]),
+    ("nasty green", [112, 178, 63]),
+    ("navy", [1, 21, 62]),
+    ("navy blue", [0, 17, 70]),
+    ("navy green", [53, 83, 10]),
+    ("neon blue", [4, 217, 255]),
+    ("neon green", [12, 255, 12]),
+    ("neon pink", [254, 1, 154]),
+    ("neon purple", [188, 19, 254]),
+    ("neon red", [255, 7, 58]),
+    ("neon yellow", [207, 255, 4]),
+    ("nice blue", [16, 122, 176]),
+    ("night blue", [4, 3, 72]),
+    ("ocean", [1, 123, 146]),
+    ("ocean blue", [3, 113, 156]),
+    ("ocean green", [61, 153, 115]),
+    ("ocher", [191, 155, 12]),
+    ("ochre", [191, 144, 5]),
+    ("ocre", [198, 156, 4]),
+    ("off blue", [86, 132, 174]),
+    ("off green", [107, 163, 83]),
+    ("off white", [255, 255, 228]),
+    ("off yellow", [241, 243, 63]),
+    ("old pink", [199, 121, 134]),
+    ("old rose", [200, 127, 137]),
+    ("olive", [110, 117, 14]),
+    ("olive brown", [100, 84, 3]),
+    ("olive drab", [111, 118, 50]),
+    ("olive green", [103, 122, 4]),
+    ("olive yellow", [194, 183, 9]),
+    ("orange", [249, 115, 6]),
+    ("orange brown", [190, 100, 0]),
+    ("orangeish", [253, 141, 73]),
+    ("orange pink", [255, 111, 82]),
+    ("orange red", [253, 65, 30]),
+    ("orangered", [254, 66, 15]),
+    ("orangey brown", [177, 96, 2]),
+    ("orange yellow", [255, 173, 1]),
+    ("orangey red", [250, 66, 36]),
+    ("orangey yellow", [253, 185, 21]),
+    ("orangish", [252, 130, 74]),
+    ("orangish brown", [178, 95, 3]),
+    ("orangish red", [244, 54, 5]),
+    ("orchid", [200, 117, 196]),
+    ("pale", [255, 249, 208]),
+    ("pale aqua", [184, 255, 235]),
+    ("pale blue", [208, 254, 254]),
+    ("pale brown", [177, 145, 110]),
+    ("pale cyan", [183, 255, 250]),
+    ("pale gold", [253, 222, 108]),
+    ("pale green", [199, 253, 181]),
+    ("pale grey", [253, 253, 254]),
+    ("pale lavender", [238, 207, 254]),
+    ("pale light green", [177, 252, 153]),
+    ("pale lilac", [228, 203, 255]),
+    ("pale lime", [190, 253, 115]),
+    ("pale lime green", [177, 255, 101]),
+    ("pale magenta", [215, 103, 173]),
+    ("pale mauve", [254, 208, 252]),
+    ("pale olive", [185, 204, 129]),
+    ("pale olive green", [177, 210, 123]),
+    ("pale orange", [255, 167, 86]),
+    ("pale peach", [255, 229, 173]),
+    ("pale pink", [255, 207, 220]),
+    ("pale purple", [183, 144, 212]),
+    ("pale red", [217, 84, 77]),
+    ("pale rose", [253, 193, 197]),
+    ("pale salmon", [255, 177, 154]),
+    ("pale sky blue", [189, 246, 254]),
+    ("pale teal", [130, 203, 178]),
+    ("pale turquoise", [165, 251, 213]),
+    ("pale violet", [206, 174, 250]),
+    ("pale yellow", [255, 255, 132]),
+    ("parchment", [254, 252, 175]),
+    ("pastel blue", [162, 191, 254]),
+    ("pastel green", [176, 255, 157]),
+    ("pastel orange", [255, 150, 79]),
+    ("pastel pink", [255, 186, 205]),
+    ("pastel purple", [202, 160, 255]),
+    ("pastel red", [219, 88, 86]),
+    ("pastel yellow", [255, 254, 113]),
+    ("pea", [164, 191, 32]),
+    ("peach", [255, 176, 124]),
+    ("peachy pink", [255, 154, 138]),
+    ("peacock blue", [1, 103, 149]),
+    ("pea green", [142, 171, 18]),
+    ("pear", [203, 248, 95]),
+    ("pea soup", [146, 153, 1]),
+    ("pea soup green", [148, 166, 23]),
+    ("periwinkle", [142, 130, 254]),
+    ("periwinkle blue", [143, 153, 251]),
+    ("perrywinkle", [143, 140, 231]),
+    ("petrol", [0, 95, 106]),
+    ("pig pink", [231, 142, 165]),
+    ("pine", [43, 93, 52]),
+    ("pine green", [10, 72, 30]),
+    ("pink", [255, 129, 192]),
+    ("pink/purple", [239, 29, 231]),
+    ("pinkish", [212, 106, 126]),
+    ("pinkish brown", [177, 114, 97]),
+    ("pinkish grey", [200, 172, 169]),
+    ("pinkish orange", [255, 114, 76]),
+    ("pinkish purple", [214, 72, 215]),
+    ("pinkish red", [241, 12, 69]),
+    ("pinkish tan", [217, 155, 130]),
+    ("pink purple", [219, 75, 218]),
+    ("pink red", [245, 5, 79]),
+    ("pinky", [252, 134, 170]),
+    ("pinky purple", [201, 76, 190]),
+    ("pinky red", [252, 38, 71]),
+    ("piss yellow", [221, 214, 24]),
+    ("pistachio", [192, 250, 139]),
+    ("plum", [88, 15, 65]),
+    ("plum purple", [78, 5, 80]),
+    ("poison green", [64, 253, 20]),
+    ("poo", [143, 115, 3]),
+    ("poo brown", [136, 95, 1]),
+    ("poop", [127, 94, 0]),
+    ("poop brown", [122, 89, 1]),
+    ("poop green", [111, 124, 0]),
+    ("powder blue", [177, 209, 252]),
+    ("powder pink", [255, 178, 208]),
+    ("primary blue", [8, 4, 249]),
+    ("prussian blue", [0, 69, 119]),
+    ("puce", [165, 126, 82]),
+    ("puke", [165, 165, 2]),
+    ("puke brown", [148, 119, 6]),
+    ("puke green", [154, 174, 7]),
+    ("puke yellow", [194, 190, 14]),
+    ("pumpkin", [225, 119, 1]),
+    ("pumpkin orange", [251, 125, 7]),
+    ("pure blue", [2, 3, 226]),
+    ("purple", [126, 30, 156]),
+    ("purple/blue", [93, 33, 208]),
+    ("purple/pink", [215, 37, 222]),
+    ("purple blue", [99, 45, 233]),
+    ("purple brown", [103, 58, 63]),
+    ("purple grey", [134, 111, 133]),
+    ("purpleish", [152, 86, 141]),
+    ("purpleish blue", [97, 64, 239]),
+    ("purpleish pink", [223, 78, 200]),
+    ("purple pink", [224, 63, 216]),
+    ("purple red", [153, 1, 71]),
+    ("purpley", [135, 86, 228]),
+    ("purpley blue", [95, 52, 231]),
+    ("purpley grey", [148, 126, 148]),
+    ("purpley pink", [200, 60, 185]),
+    ("purplish", [148, 86, 140]),
+    ("purplish blue", [96, 30, 249]),
+    ("purplish brown", [107, 66, 71]),
+    ("purplish grey", [122, 104, 127]),
+    ("purplish pink", [206, 93, 174]),
+    ("purplish red", [176, 5, 75]),
+    ("purply", [152, 63, 178]),
+    ("purply blue", [102, 26, 238]),
+    ("purply pink", [240, 117, 230]),
+    ("putty", [190, 174, 138]),
+    ("racing green", [1, 70, 0]),
+    ("radioactive green", [44, 250, 31]),
+    ("raspberry", [176, 1, 73]),
+    ("raw sienna", [154, 98, 0]),
+    ("raw umber", [167, 94, 9]),
+    ("really light blue", [212, 255, 255]),
+    ("red", [229, 0, 0]),
+    ("red brown", [139, 46, 22]),
+    ("reddish", [196, 66, 64]),
+    ("reddish brown", [127, 43, 10]),
+    ("reddish grey", [153, 117, 112]),
+    ("reddish orange", [248, 72, 28]),
+    ("reddish pink", [254, 44, 84]),
+    ("reddish purple", [145, 9, 81]),
+    ("reddy brown", [110, 16, 5]),
+    ("red orange", [253, 60, 6]),
+    ("red pink", [250, 42, 85]),
+    ("red purple", [130, 7, 71]),
+    ("red violet", [158, 1, 104]),
+    ("red wine", [140, 0, 52]),
+    ("rich blue", [2, 27, 249]),
+    ("rich purple", [114, 0, 88]),
+    ("robin's egg", [109, 237, 253]),
+    ("robin's egg blue", [152, 239, 249]),
+    ("robin egg blue", [138, 241, 254]),
+    ("rosa", [254, 134, 164]),
+    ("rose", [207, 98, 117]),
+    ("rose pink", [247, 135, 154]),
+    ("rose red", [190, 1, 60]),
+    ("rosy pink", [246, 104, 142]),
+    ("rouge", [171, 18, 57]),
+    ("royal", [12, 23, 147]),
+    ("royal blue", [5, 4, 170]),
+    ("royal purple", [75, 0, 110]),
+    ("ruby", [202, 1, 71]),
+    ("russet", [161, 57, 5]),
+    ("rust", [168, 60, 9]),
+    ("rust brown", [139, 49, 3]),
+    ("rust orange", [196, 85, 8]),
+    ("rust red", [170, 39, 4]),
+    ("rusty orange", [205, 89, 9]),
+    ("rusty red", [175, 47, 13]),
+    ("saffron", [254, 178, 9]),
+    ("sage", [135, 174, 115]),
+    ("sage green", [136, 179, 120]),
+    ("salmon", [255, 121, 108]),
+    ("salmon pink", [254, 123, 124]),
+    ("sand", [226, 202, 118]),
+    ("sand brown", [203, 165, 96]),
+    ("sandstone", [201, 174, 116]),
+    ("sandy", [241, 218, 122]),
+    ("sandy brown", [196, 166, 97]),
+    ("sand yellow", [252, 225, 102]),
+    ("sandy yellow", [253, 238, 115]),
+    ("sap green", [92, 139, 21]),
+    ("sapphire", [33, 56, 171]),
+    ("scarlet", [190, 1, 25]),
+    ("sea", [60, 153, 146]),
+    ("sea blue", [4, 116, 149]),
+    ("seafoam", [128, 249, 173]),
+    ("seafoam blue", [120, 209, 182]),
+    ("seafoam green", [122, 249, 171]),
+    ("sea green", [83, 252, 161]),
+    ("seaweed", [24, 209, 123]),
+    ("seaweed green", [53, 173, 107]),
+    ("sepia", [152, 94, 43]),
+    ("shamrock", [1, 180, 76]),
+    ("shamrock green", [2, 193, 77]),
+    ("shit", [127, 95, 0]),
+    ("shit brown", [123, 88, 4]),
+    ("shit green", [117, 128, 0]),
+    ("shocking pink", [254, 2, 162]),
+    ("sick green", [157, 185, 44]),
+    ("sickly green", [148, 178, 28]),
+    ("sickly yellow", [208, 228, 41]),
+    ("sienna", [169, 86, 30]),
+    ("silver", [197, 201, 199]),
+    ("sky", [130, 202, 252]),
+    ("sky blue", [117, 187, 253]),
+    ("slate", [81, 101, 114]),
+    ("slate blue", [91, 124, 153]),
+    ("slate green", [101, 141, 109]),
+    ("slate grey", [89, 101, 109]),
+    ("slime green", [153, 204, 4]),
+    ("snot", [172, 187, 13]),
+    ("snot green", [157, 193, 0]),
+    ("soft blue", [100, 136, 234]),
+    ("soft green", [111, 194, 118]),
+    ("soft pink", [253, 176, 192]),
+    ("soft purple", [166, 111, 181]),
+    ("spearmint", [30, 248, 118]),
+    ("spring green", [169, 249, 113]),
+    ("spruce", [10, 95, 56]),
+    ("squash", [242, 171, 21]),
+    ("steel", [115, 133, 149]),
+    ("steel blue", [90, 125, 154]),
+    ("steel grey", [111, 130, 138]),
+    ("stone", [173, 165, 135]),
+    ("stormy blue", [80, 123, 156]),
+    ("straw", [252, 246, 121]),
+    ("strawberry", [251, 41, 67]),
+    ("strong blue", [12, 6, 247]),
+    ("strong pink", [255, 7, 137]),
+    ("sunflower", [255, 197, 18]),
+    ("sunflower yellow", [255, 218, 3]),
+    ("sunny yellow", [255, 249, 23]),
+    ("sunshine yellow", [255, 253, 55]),
+    ("sun yellow", [255, 223, 34]),
+    ("swamp", [105, 131, 57]),
+    ("swamp green", [116, 133, 0]),
+    ("tan", [209, 178, 111]),
+    ("tan brown", [171, 126, 76]),
+    ("tangerine", [255, 148, 8]),
+    ("tan green", [169, 190, 112]),
+    ("taupe", [185, 162, 129]),
+    ("tea", [101, 171, 124]),
+    ("tea green", [189, 248, 163]),
+    ("teal", [2, 147, 134]),
+    ("teal blue", [1, 136, 159]),
+    ("teal green", [37, 163, 111]),
+    ("tealish", [36, 188, 168]),
+    ("tealish green", [12, 220, 115]),
+    ("terracota", [203, 104, 67]),
+    ("terra cotta", [201, 100, 59]),
+    ("terracotta", [202, 102, 65]),
+    ("tiffany blue", [123, 242, 218]),
+    ("tomato", [239, 64, 38]),
+    ("tomato red", [236, 45, 1]),
+    ("topaz", [19, 187, 175]),
+    ("toupe", [199, 172, 125]),
+    ("toxic green", [97, 222, 42]),
+    ("tree green", [42, 126, 25]),
+    ("true blue", [1, 15, 204]),
+    ("true green", [8, 148, 4]),
+    ("turquoise", [6, 194, 172]),
+    ("turquoise blue", [6, 177, 196]),
+    ("turquoise green", [4, 244, 137]),
+    ("turtle green", [117, 184, 79]),
+    ("twilight", [78, 81, 139]),
+    ("twilight blue", [10, 67, 122]),
+    ("ugly blue", [49, 102, 138]),
+    ("ugly brown", [125, 113, 3]),
+    ("ugly green", [122, 151, 3]),
+    ("ugly pink", [205, 117, 132]),
+    ("ugly purple", [164, 66, 160]),
+    ("ugly yellow", [208, 193, 1]),
+    ("ultramarine", [32, 0, 177]),
+    ("ultramarine blue", [24, 5, 219]),
+    ("umber", [178, 100, 0]),
+    ("velvet", [117, 8, 81]),
+    ("vermillion", [244, 50, 12]),
+    ("very dark blue", [0, 1, 51]),
+    ("very dark brown", [29, 2, 0]),
+    ("very dark green", [6, 46, 3]),
+    ("very dark purple", [42, 1, 52]),
+    ("very light blue", [213, 255, 255]),
+    ("very light brown", [211, 182, 131]),
+    ("very light green", [209, 255, 189]),
+    ("very light pink", [255, 244, 242]),
+    ("very light purple", [246, 206, 252]),
+    ("very pale blue", [214, 255, 254]),
+    ("very pale green", [207, 253, 188]),
+    ("vibrant blue", [3, 57, 248]),
+    ("vibrant green", [10, 221, 8]),
+    ("vibrant purple", [173, 3, 222]),
+    ("violet", [154, 14, 234]),
+    ("violet blue", [81, 10, 201]),
+    ("violet pink", [251, 95, 252]),
+    ("violet red", [165, 0, 85]),
+    ("viridian", [30, 145, 103]),
+    ("vivid blue", [21, 46, 255]),
+    ("vivid green", [47, 239, 16]),
+    ("vivid purple", [153, 0, 250]),
+    ("vomit", [162, 164, 21]),
+    ("vomit green", [137, 162, 3]),
+    ("vomit yellow", [199, 193, 12]),
+    ("warm blue", [75, 87, 219]),
+    ("warm brown", [150, 78, 2]),
+    ("warm grey", [151, 138, 132]),
+    ("warm pink", [251, 85, 129]),
+    ("warm purple", [149, 46, 143]),
+    ("washed out green", [188, 245, 166]),
+    ("water blue", [14, 135, 204]),
+    ("watermelon", [253, 70, 89]),
+    ("weird green", [58, 229, 127]),
+    ("wheat", [251, 221, 126]),
+    ("white", [255, 255, 255]),
+    ("windows blue", [55, 120, 191]),
+    ("wine", [128, 1, 63]),
+    ("wine red", [123, 3, 35]),
+    ("wintergreen", [32, 249, 134]),
+    ("wisteria", [168, 125, 194]),
+    ("yellow", [255, 255, 20]),
+    ("yellow/green", [200, 253, 61]),
+    ("yellow brown", [183, 148, 0]),
+    ("yellow green", [192, 251, 45]),
+    ("yellowgreen", [187, 249, 15]),
+    ("yellowish", [250, 238, 102]),
+    ("yellowish brown", [155, 122, 1]),
+    ("yellowish green", [176, 221, 22]),
+    ("yellowish orange", [255, 171, 15]),
+    ("yellowish tan", [252, 252, 129]),
+    ("yellow ochre", [203, 157, 6]),
+    ("yellow orange", [252, 176, 1]),
+    ("yellow tan", [255, 227, 110]),
+    ("yellowy brown", [174, 139, 12]),
+    ("yellowy green", [191, 241, 40]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOTH: &[NameDatabase] = &[NameDatabase::Css, NameDatabase::X11];
+
+    #[test]
+    fn x11_is_sorted() {
+        assert!(X11_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn xkcd_is_sorted() {
+        let keys = XKCD_COLORS.map(|(name, _)| (key(name), name));
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn xkcd_names() {
+        let xkcd = NameDatabase::load("xkcd").unwrap();
+        assert_eq!(xkcd.colors().count(), 949);
+        assert_eq!(xkcd.get("Cloudy Blue"), Some(Srgb::new(0xac, 0xc2, 0xd9)));
+        assert_eq!(xkcd.get("robin's egg"), Some(Srgb::new(0x6d, 0xed, 0xfd)));
+        assert_eq!(xkcd.get("eggshell"), Some(Srgb::new(0xff, 0xff, 0xd4)));
+        assert_eq!(xkcd.get("egg shell"), Some(Srgb::new(0xff, 0xfc, 0xc4)));
+        assert_eq!(xkcd.get("Egg Shell"), Some(Srgb::new(0xff, 0xfc, 0xc4)));
+        assert_eq!(xkcd.get("slateblue3"), None);
+
+        let databases = [xkcd];
+        assert_eq!(
+            nearest_name(Srgb::new(0x7e, 0x1e, 0x9c), true, &databases),
+            Some("purple")
+        );
+        assert_eq!(
+            nearest_name(Srgb::new(0xad, 0xc2, 0xd9), false, &databases),
+            Some("cloudy blue")
+        );
+    }
+
+    #[test]
+    fn lookup_ignores_case_and_spaces() {
+        let slateblue3 = Some(Srgb::new(105, 89, 205));
+        assert_eq!(NameDatabase::X11.get("SlateBlue3"), slateblue3);
+        assert_eq!(NameDatabase::X11.get("slate blue 3"), slateblue3);
+        assert_eq!(
+            NameDatabase::Css.get("Dark Blue"),
+            Some(Srgb::new(0, 0, 139))
+        );
+        assert_eq!(NameDatabase::Css.get("slateblue3"), None);
+    }
+
+    #[test]
+    fn databases_differ() {
+        // X11 gray is lighter than the CSS one
+        assert_eq!(
+            NameDatabase::Css.get("gray"),
+            Some(Srgb::new(128, 128, 128))
+        );
+        assert_eq!(
+            NameDatabase::X11.get("gray"),
+            Some(Srgb::new(190, 190, 190))
+        );
+    }
+
+    #[test]
+    fn exact_names() {
+        assert_eq!(
+            nearest_name(Srgb::new(255, 0, 0), true, DEFAULT_NAMES),
+            Some("red")
+        );
+        assert_eq!(
+            nearest_name(Srgb::new(250, 128, 114), true, DEFAULT_NAMES),
+            Some("salmon")
+        );
+        assert_eq!(
+            nearest_name(Srgb::new(128, 128, 128), true, DEFAULT_NAMES),
+            Some("gray")
+        );
+        assert_eq!(
+            nearest_name(Srgb::new(254, 0, 0), true, DEFAULT_NAMES),
+            None
+        );
+        assert_eq!(
+            nearest_name(Srgb::new(105, 89, 205), true, BOTH),
+            Some("slateblue3")
+        );
+    }
+
+    #[test]
+    fn nearest_names() {
+        assert_eq!(
+            nearest_name(Srgb::new(254, 1, 0), false, DEFAULT_NAMES),
+            Some("red")
+        );
+        assert_eq!(
+            nearest_name(Srgb::new(0, 0, 140), false, DEFAULT_NAMES),
+            Some("darkblue")
+        );
+        assert_eq!(
+            nearest_name(Srgb::new(3, 3, 3), false, DEFAULT_NAMES),
+            Some("black")
+        );
+        assert_eq!(
+            nearest_name(Srgb::new(106, 89, 204), false, BOTH),
+            Some("slateblue3")
+        );
+    }
+
+    #[test]
+    fn rgb_txt_formats() {
+        let src = "! X11 comment\n106 90 205\t\tSlateBlue\n\n# xkcd comment\nslate blue\t#5b7c99\n";
+        let colors = parse_rgb_txt(src).unwrap();
+        let expected = [
+            ("SlateBlue".to_owned(), Srgb::new(106, 90, 205)),
+            ("slate blue".to_owned(), Srgb::new(0x5b, 0x7c, 0x99)),
+        ];
+        assert_eq!(colors, expected);
+
+        let xkcd =
+            "License: http://creativecommons.org/publicdomain/zero/1.0/\ncloudy blue\t#acc2d9\n";
+        assert_eq!(
+            parse_rgb_txt(xkcd).unwrap(),
+            [("cloudy blue".to_owned(), Srgb::new(0xac, 0xc2, 0xd9))]
+        );
+
+        for src in ["106 90 SlateBlue", "106 90 205", "300 0 0 red", "red #ff00"] {
+            assert!(parse_rgb_txt(src).is_err(), "{src}");
+        }
+    }
+
+    #[test]
+    fn database_as_palette() {
+        let palette = NameDatabase::X11.palette();
+        assert_eq!(palette.len(), X11_COLORS.len());
+        assert_eq!(palette["slateblue3"], "#6959CD");
+    }
+}
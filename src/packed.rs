@@ -0,0 +1,164 @@
+//! Colors packed into a single `u32`
+//!
+//! As input `0xrrggbb` and `0xaarrggbb` are accepted as is, any packing can be
+//! given as a function like `argb-int(4294901760)` or `bgr-int(0xff0000)`.
+//! As output the packings are formats that emit a decimal integer
+
+use crate::Result;
+use crate::parser::{ParseError, ParseErrorKind, offset_of};
+use palette::rgb::channels::{Abgr, Argb, Rgba};
+use palette::{Srgb, Srgba, WithAlpha};
+
+const RGB_MASK: u32 = 0x00FF_FFFF;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Packing {
+    /// `0x00RRGGBB`
+    Rgb,
+    /// `0xAARRGGBB`, Android and Compose
+    Argb,
+    /// `0xRRGGBBAA`
+    Rgba,
+    /// `0x00BBGGRR`, Win32 `COLORREF`
+    Bgr,
+    /// `0xAABBGGRR`
+    Abgr,
+}
+
+impl Packing {
+    /// Format and function name of the packing, `rgb-int`, `argb-int` and so on
+    pub fn from_name(name: &str) -> Option<Self> {
+        let packing = match name {
+            "rgb-int" => Self::Rgb,
+            "argb-int" => Self::Argb,
+            "rgba-int" => Self::Rgba,
+            "bgr-int" => Self::Bgr,
+            "abgr-int" => Self::Abgr,
+            _ => return None,
+        };
+
+        Some(packing)
+    }
+
+    pub fn pack(self, color: Srgba<u8>) -> u32 {
+        match self {
+            Self::Rgb => color.into_u32::<Argb>() & RGB_MASK,
+            Self::Argb => color.into_u32::<Argb>(),
+            Self::Rgba => color.into_u32::<Rgba>(),
+            Self::Bgr => color.into_u32::<Abgr>() & RGB_MASK,
+            Self::Abgr => color.into_u32::<Abgr>(),
+        }
+    }
+
+    /// Returns `None` if a packing without alpha has bits set above the color
+    pub fn unpack(self, value: u32) -> Option<Srgba<u8>> {
+        let color = match self {
+            Self::Rgb | Self::Bgr if value > RGB_MASK => return None,
+            Self::Rgb => Srgb::from_u32::<Argb>(value).with_alpha(u8::MAX),
+            Self::Argb => Srgba::from_u32::<Argb>(value),
+            Self::Rgba => Srgba::from_u32::<Rgba>(value),
+            Self::Bgr => Srgb::from_u32::<Abgr>(value).with_alpha(u8::MAX),
+            Self::Abgr => Srgba::from_u32::<Abgr>(value),
+        };
+
+        Some(color)
+    }
+}
+
+/// Returns `None` if `src` isn't a packed color at all
+pub fn parse(src: &str) -> Option<Result<Srgba<u8>>> {
+    if let Some(digits) = src.strip_prefix("0x").or_else(|| src.strip_prefix("0X")) {
+        let packing = match digits.len() {
+            6 => Packing::Rgb,
+            _ => Packing::Argb,
+        };
+
+        let color = u32::from_str_radix(digits, 16)
+            .ok()
+            .filter(|_| matches!(digits.len(), 6 | 8))
+            .and_then(|value| packing.unpack(value))
+            .ok_or_else(|| ParseError::expected(src, 2, "6 or 8 hex digits").into());
+
+        return Some(color);
+    }
+
+    let (name, value) = src.split_once('(')?;
+    let packing = Packing::from_name(&name.trim().to_ascii_lowercase())?;
+
+    let Some(value) = value.trim_end().strip_suffix(')').map(str::trim) else {
+        return Some(Err(ParseError::expected(src, src.len(), "`)`").into()));
+    };
+
+    let offset = offset_of(src, value);
+    let Some(int) = parse_int(value) else {
+        return Some(Err(ParseError::expected(
+            src,
+            offset,
+            "a decimal or `0x` hexadecimal integer",
+        )
+        .into()));
+    };
+
+    let color = packing.unpack(int).ok_or_else(|| {
+        let kind = ParseErrorKind::OutOfRange {
+            function: name.trim().to_owned(),
+            index: 1,
+            value: value.to_owned(),
+        };
+
+        ParseError::new(src, offset, kind).into()
+    });
+
+    Some(color)
+}
+
+/// Decimal or `0x` prefixed hexadecimal
+fn parse_int(src: &str) -> Option<u32> {
+    match src.strip_prefix("0x").or_else(|| src.strip_prefix("0X")) {
+        Some(digits) => u32::from_str_radix(digits, 16).ok(),
+        None => src.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLOR: Srgba<u8> = Srgba::new(0x12, 0x34, 0x56, 0x78);
+
+    fn parsed(src: &str) -> Srgba<u8> {
+        parse(src).unwrap().unwrap()
+    }
+
+    #[test]
+    fn pack() {
+        assert_eq!(Packing::Rgb.pack(COLOR), 0x0012_3456);
+        assert_eq!(Packing::Argb.pack(COLOR), 0x7812_3456);
+        assert_eq!(Packing::Rgba.pack(COLOR), 0x1234_5678);
+        assert_eq!(Packing::Bgr.pack(COLOR), 0x0056_3412);
+        assert_eq!(Packing::Abgr.pack(COLOR), 0x7856_3412);
+    }
+
+    #[test]
+    fn unpack() {
+        for packing in [Packing::Argb, Packing::Rgba, Packing::Abgr] {
+            assert_eq!(packing.unpack(packing.pack(COLOR)), Some(COLOR));
+        }
+
+        let opaque = Srgba::new(0x12, 0x34, 0x56, 0xff);
+        assert_eq!(Packing::Bgr.unpack(0x0056_3412), Some(opaque));
+        assert_eq!(Packing::Rgb.unpack(0x0100_0000), None);
+    }
+
+    #[test]
+    fn parse_packed() {
+        assert_eq!(parsed("0x123456"), Srgba::new(0x12, 0x34, 0x56, 0xff));
+        assert_eq!(parsed("0X78123456"), COLOR);
+        assert_eq!(parsed("argb-int(2014458966)"), COLOR);
+        assert_eq!(parsed("RGBA-INT( 0x12345678 )"), COLOR);
+        assert!(parse("0x12345").unwrap().is_err());
+        assert!(parse("rgb-int(0x1000000)").unwrap().is_err());
+        assert!(parse("rgb(1 2 3)").is_none());
+        assert!(parse("red").is_none());
+    }
+}
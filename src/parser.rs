@@ -0,0 +1,795 @@
+use crate::{Error, Result};
+use itertools::Itertools;
+
+/// How a single parsed [`Value`] is turned into a color component
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Component {
+    /// `scale` is applied to bare numbers and `percent` is what `100%` resolves to,
+    /// the resolved value has to be within `min..=max`
+    Number {
+        scale: f32,
+        percent: f32,
+        min: f32,
+        max: f32,
+    },
+    /// Angle in degrees, bare numbers are degrees as well
+    Hue,
+}
+
+/// A single argument of a color function
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Number(f32),
+    Percent(f32),
+    /// Angle normalized to degrees
+    Angle(f32),
+    /// The `none` keyword, resolves to 0
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Value(Value),
+    Comma,
+    Slash,
+}
+
+/// A token with where it is in the source and how it was written
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Spanned<'a, T> {
+    item: T,
+    offset: usize,
+    text: &'a str,
+}
+
+/// Color function in CSS Color Level 4 notation
+///
+/// Supports both the legacy comma syntax `rgb(255, 0, 0)` and the modern
+/// space syntax with an optional alpha after a slash `rgb(255 0 0 / 50%)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function<'a> {
+    src: &'a str,
+    name: Spanned<'a, String>,
+    space: Option<Spanned<'a, String>>,
+    values: Vec<Spanned<'a, Value>>,
+    /// Offset of the slash before the alpha
+    slash: Option<usize>,
+    /// Offset of the closing parenthesis
+    close: usize,
+    /// Values are separated by commas
+    legacy: bool,
+}
+
+/// Where and why a color failed to parse
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("Failed to parse color `{src}` at byte {offset}: {kind}")]
+pub struct ParseError {
+    pub src: String,
+    /// Byte offset into `src`
+    pub offset: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseErrorKind {
+    #[error("expected {expected}, found {found}")]
+    Expected {
+        expected: &'static str,
+        found: String,
+    },
+
+    #[error("{function}() takes {expected} values, found {found}")]
+    Count {
+        function: String,
+        expected: String,
+        found: usize,
+    },
+
+    /// `index` counts from 1
+    #[error("component {index} of {function}() can't be `{value}`")]
+    Component {
+        function: String,
+        index: usize,
+        value: String,
+    },
+
+    #[error("component {index} of {function}() is out of range: `{value}`")]
+    OutOfRange {
+        function: String,
+        index: usize,
+        value: String,
+    },
+
+    #[error("unknown {what} `{name}`{}", .suggestion.as_ref().map(|s| format!(", did you mean `{s}`?")).unwrap_or_default())]
+    Unknown {
+        what: &'static str,
+        name: String,
+        suggestion: Option<String>,
+    },
+}
+
+pub trait FromComponent: Sized {
+    /// Component used for an alpha value given after a slash
+    const ALPHA: Component;
+
+    fn from_component(value: f32) -> Option<Self>;
+}
+
+impl FromComponent for u8 {
+    const ALPHA: Component = Component::Number {
+        scale: 255.0,
+        percent: 255.0,
+        min: 0.0,
+        max: 255.0,
+    };
+
+    fn from_component(value: f32) -> Option<Self> {
+        let value = value.round();
+
+        (0.0..=255.0).contains(&value).then_some(value as u8)
+    }
+}
+
+impl FromComponent for f32 {
+    const ALPHA: Component = Component::Number {
+        scale: 1.0,
+        percent: 1.0,
+        min: 0.0,
+        max: 1.0,
+    };
+
+    fn from_component(value: f32) -> Option<Self> {
+        value.is_finite().then_some(value)
+    }
+}
+
+impl Component {
+    pub fn resolve(self, value: Value) -> Option<f32> {
+        match (self, value) {
+            (_, Value::None) => Some(0.0),
+            (Self::Number { scale, .. }, Value::Number(n)) => Some(n * scale),
+            (Self::Number { percent, .. }, Value::Percent(p)) => Some(p / 100.0 * percent),
+            (Self::Hue, Value::Number(n) | Value::Angle(n)) => Some(n),
+            _ => None,
+        }
+    }
+}
+
+impl Value {
+    pub fn parse(src: &str) -> Option<Self> {
+        if src.eq_ignore_ascii_case("none") {
+            return Some(Self::None);
+        }
+
+        let end = number_len(src)?;
+        let number = src[..end].parse::<f32>().ok()?;
+
+        let value = match src[end..].to_ascii_lowercase().as_str() {
+            "" => Self::Number(number),
+            "%" => Self::Percent(number),
+            "deg" => Self::Angle(number),
+            "rad" => Self::Angle(number.to_degrees()),
+            "grad" => Self::Angle(number * 0.9),
+            "turn" => Self::Angle(number * 360.0),
+            _ => return None,
+        };
+
+        Some(value)
+    }
+}
+
+impl ParseError {
+    pub fn new(src: &str, offset: usize, kind: ParseErrorKind) -> Self {
+        Self {
+            src: src.to_owned(),
+            offset,
+            kind,
+        }
+    }
+
+    pub fn expected(src: &str, offset: usize, expected: &'static str) -> Self {
+        let rest = src[offset..].trim_start();
+        let delimiter = |c: char| c.is_whitespace() || matches!(c, ',' | '/' | '(' | ')');
+
+        // a delimiter on its own or everything up to the next one
+        let len = match rest.chars().next() {
+            None => 0,
+            Some(c) if delimiter(c) => c.len_utf8(),
+            Some(_) => rest.find(delimiter).unwrap_or(rest.len()),
+        };
+
+        let found = match &rest[..len] {
+            "" => "end of input".to_owned(),
+            found => format!("`{found}`"),
+        };
+
+        Self::new(src, offset, ParseErrorKind::Expected { expected, found })
+    }
+
+    /// Unknown name of a `what`, with the closest of `known` as a suggestion
+    pub fn unknown<'b>(
+        src: &str,
+        offset: usize,
+        what: &'static str,
+        name: &str,
+        known: impl IntoIterator<Item = &'b str>,
+    ) -> Self {
+        let kind = ParseErrorKind::Unknown {
+            what,
+            name: name.to_owned(),
+            suggestion: suggest(name, known).map(str::to_owned),
+        };
+
+        Self::new(src, offset, kind)
+    }
+}
+
+impl<'a> Function<'a> {
+    /// Returns `None` if `src` isn't in function notation at all
+    pub fn parse(src: &'a str) -> Result<Option<Self>> {
+        let Some(open) = src.find('(') else {
+            return Ok(None);
+        };
+
+        let name = src[..open].trim();
+
+        if name.is_empty() {
+            return Err(ParseError::expected(src, 0, "a function name").into());
+        }
+
+        let close = match src[open + 1..].find(['(', ')']).map(|i| open + 1 + i) {
+            Some(close) if src[close..].starts_with(')') => close,
+            Some(nested) => return Err(ParseError::expected(src, nested, "`)`").into()),
+            None => return Err(ParseError::expected(src, src.len(), "`)`").into()),
+        };
+
+        let trailing = src[close + 1..].trim_start();
+
+        if !trailing.is_empty() {
+            let offset = src.len() - trailing.len();
+
+            return Err(ParseError::expected(src, offset, "end of input").into());
+        }
+
+        let name = Spanned {
+            item: name.to_ascii_lowercase(),
+            offset: offset_of(src, name),
+            text: name,
+        };
+
+        let mut inner = &src[open + 1..close];
+
+        // `color(display-p3 1 0 0)` names its color space as the first argument
+        let space = if name.item == "color" {
+            let rest = inner.trim_start();
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+
+            if rest[..end].is_empty() {
+                return Err(ParseError::expected(src, close, "a color space").into());
+            }
+
+            inner = &rest[end..];
+
+            Some(Spanned {
+                item: rest[..end].to_ascii_lowercase(),
+                offset: offset_of(src, rest),
+                text: &rest[..end],
+            })
+        } else {
+            None
+        };
+
+        let tokens = tokenize(src, inner)?;
+        let expected =
+            |offset: usize, expected| Err(ParseError::expected(src, offset, expected).into());
+
+        let mut values = Vec::with_capacity(tokens.len());
+        let mut slash = None;
+        let legacy = tokens.iter().any(|token| token.item == Token::Comma);
+
+        if legacy {
+            // legacy syntax, values separated by commas
+            for (i, token) in tokens.iter().enumerate() {
+                match (i % 2, token.item) {
+                    (0, Token::Value(value)) if space.is_none() => values.push(Spanned {
+                        item: value,
+                        offset: token.offset,
+                        text: token.text,
+                    }),
+                    (0, _) => return expected(token.offset, "a value"),
+                    (1, Token::Comma) => {}
+                    _ => return expected(token.offset, "`,`"),
+                }
+            }
+
+            if tokens.len() % 2 == 0 {
+                return expected(close, "a value");
+            }
+        } else {
+            // modern syntax, values separated by whitespace with an optional alpha after a slash
+            for token in &tokens {
+                match token.item {
+                    Token::Value(value) => values.push(Spanned {
+                        item: value,
+                        offset: token.offset,
+                        text: token.text,
+                    }),
+                    Token::Slash if slash.is_none() && !values.is_empty() => {
+                        slash = Some(token.offset)
+                    }
+                    _ => return expected(token.offset, "a value"),
+                }
+            }
+
+            match slash {
+                Some(offset) if values.last().is_none_or(|value| value.offset < offset) => {
+                    return expected(close, "an alpha value");
+                }
+                Some(offset) if values.iter().filter(|value| value.offset > offset).count() > 1 => {
+                    let extra = values.iter().filter(|value| value.offset > offset).nth(1);
+
+                    return expected(extra.map_or(close, |value| value.offset), "`)`");
+                }
+                _ => {}
+            }
+        }
+
+        if values.is_empty() {
+            return expected(close, "a value");
+        }
+
+        Ok(Some(Self {
+            src,
+            name,
+            space,
+            values,
+            slash,
+            close,
+            legacy,
+        }))
+    }
+
+    /// Lowercase function name
+    pub fn name(&self) -> &str {
+        &self.name.item
+    }
+
+    /// Color space of the `color()` function
+    pub fn space(&self) -> Option<&str> {
+        self.space.as_ref().map(|space| space.item.as_str())
+    }
+
+    /// Amount of values including the alpha after a slash
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Whether the values are separated by commas, `rgb(255, 0, 0)`
+    pub fn is_legacy(&self) -> bool {
+        self.legacy
+    }
+
+    /// Error for a function name that isn't one of `known`
+    pub fn unknown_name<'b>(&self, known: impl IntoIterator<Item = &'b str>) -> Error {
+        ParseError::unknown(
+            self.src,
+            self.name.offset,
+            "function",
+            self.name.text,
+            known,
+        )
+        .into()
+    }
+
+    /// Error for a `color()` space that isn't one of `known`
+    pub fn unknown_space<'b>(&self, known: impl IntoIterator<Item = &'b str>) -> Error {
+        let Some(space) = &self.space else {
+            return self.unknown_name(known);
+        };
+
+        ParseError::unknown(self.src, space.offset, "color space", space.text, known).into()
+    }
+
+    /// Fails unless the function has one of the amounts of values in `lens`
+    pub fn expect_len(&self, lens: &[usize]) -> Result<()> {
+        if lens.contains(&self.len()) {
+            return Ok(());
+        }
+
+        // points at the first value too many, or the end when there are too few
+        let max = lens.iter().copied().max().unwrap_or_default();
+        let offset = self
+            .values
+            .get(max)
+            .map_or(self.close, |value| value.offset);
+
+        let kind = ParseErrorKind::Count {
+            function: self.name.item.clone(),
+            expected: lens.iter().map(usize::to_string).join(" or "),
+            found: self.len(),
+        };
+
+        Err(ParseError::new(self.src, offset, kind).into())
+    }
+
+    /// Resolves exactly `N` values, a slash is not allowed
+    pub fn params<T: FromComponent, const N: usize>(
+        &self,
+        components: [Component; N],
+    ) -> Result<[T; N]> {
+        if let Some(slash) = self.slash {
+            return Err(ParseError::expected(self.src, slash, "a value").into());
+        }
+
+        self.resolve(components)
+    }
+
+    /// Resolves exactly `N` values where the last one is alpha,
+    /// when it's given after a slash it's resolved with [`FromComponent::ALPHA`]
+    pub fn params_with_alpha<T: FromComponent, const N: usize>(
+        &self,
+        mut components: [Component; N],
+    ) -> Result<[T; N]> {
+        if self.slash.is_some()
+            && let Some(last) = components.last_mut()
+        {
+            *last = T::ALPHA;
+        }
+
+        self.resolve(components)
+    }
+
+    fn resolve<T: FromComponent, const N: usize>(
+        &self,
+        components: [Component; N],
+    ) -> Result<[T; N]> {
+        self.expect_len(&[N])?;
+
+        let mut params = Vec::with_capacity(N);
+
+        for (i, (value, component)) in self.values.iter().zip(components).enumerate() {
+            let err = |kind: fn(String, usize, String) -> ParseErrorKind| {
+                let kind = kind(self.name.item.clone(), i + 1, value.text.to_owned());
+
+                ParseError::new(self.src, value.offset, kind)
+            };
+
+            let resolved = component.resolve(value.item).ok_or_else(|| {
+                err(|function, index, value| ParseErrorKind::Component {
+                    function,
+                    index,
+                    value,
+                })
+            })?;
+
+            let in_range = match component {
+                Component::Number { min, max, .. } => (min..=max).contains(&resolved),
+                Component::Hue => true,
+            };
+
+            let param = T::from_component(resolved)
+                .filter(|_| in_range)
+                .ok_or_else(|| {
+                    err(|function, index, value| ParseErrorKind::OutOfRange {
+                        function,
+                        index,
+                        value,
+                    })
+                })?;
+
+            params.push(param);
+        }
+
+        Ok(params
+            .into_iter()
+            .collect_array::<N>()
+            .expect("the amount of values is checked"))
+    }
+}
+
+/// The closest of `known` to `name` if it's close enough to be a typo
+pub fn suggest<'b>(name: &str, known: impl IntoIterator<Item = &'b str>) -> Option<&'b str> {
+    let name = name.to_ascii_lowercase();
+    let max = (name.chars().count() / 4).max(1);
+
+    known
+        .into_iter()
+        .map(|candidate| {
+            (
+                edit_distance(&name, &candidate.to_ascii_lowercase()),
+                candidate,
+            )
+        })
+        .filter(|(distance, _)| *distance <= max)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Edit distance where swapping two adjacent characters counts as one edit
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+
+    // rows of the distances for the previous two prefixes of `a`
+    let mut prev2 = vec![0; b.len() + 1];
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (prev[j] + 1).min(row[j - 1] + 1).min(prev[j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(prev2[j - 2] + 1);
+            }
+        }
+
+        prev2 = std::mem::replace(&mut prev, row);
+    }
+
+    prev[b.len()]
+}
+
+/// Byte offset of `part` in `src`, `part` has to be a slice of `src`
+pub(crate) fn offset_of(src: &str, part: &str) -> usize {
+    part.as_ptr() as usize - src.as_ptr() as usize
+}
+
+fn tokenize<'a>(src: &'a str, inner: &'a str) -> Result<Vec<Spanned<'a, Token>>> {
+    let mut tokens = Vec::new();
+    let mut rest = inner.trim_start();
+
+    while let Some(c) = rest.chars().next() {
+        let len = match c {
+            ',' | '/' => 1,
+            _ => rest
+                .find(|c: char| c.is_whitespace() || c == ',' || c == '/')
+                .unwrap_or(rest.len()),
+        };
+
+        let text = &rest[..len];
+        let offset = offset_of(src, text);
+
+        let item = match c {
+            ',' => Token::Comma,
+            '/' => Token::Slash,
+            _ => match Value::parse(text) {
+                Some(value) => Token::Value(value),
+                None => {
+                    let expected = "a number, percentage, angle or `none`";
+
+                    return Err(ParseError::expected(src, offset, expected).into());
+                }
+            },
+        };
+
+        tokens.push(Spanned { item, offset, text });
+        rest = rest[len..].trim_start();
+    }
+
+    Ok(tokens)
+}
+
+/// Length of the `[+-]digits[.digits][e[+-]digits]` prefix
+fn number_len(src: &str) -> Option<usize> {
+    let bytes = src.as_bytes();
+    let digits = |from: usize| {
+        bytes[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+
+    let mut end = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    let int = digits(end);
+    end += int;
+
+    let mut frac = 0;
+    if bytes.get(end) == Some(&b'.') {
+        frac = digits(end + 1);
+        end += 1 + frac;
+    }
+
+    if int == 0 && frac == 0 {
+        return None;
+    }
+
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(end + 1), Some(b'+' | b'-')));
+        let exp = digits(end + 1 + sign);
+
+        if exp > 0 {
+            end += 1 + sign + exp;
+        }
+    }
+
+    Some(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Color;
+
+    const UNIT: Component = Component::Number {
+        scale: 1.0,
+        percent: 1.0,
+        min: 0.0,
+        max: 1.0,
+    };
+    const CHANNEL: Component = Component::Number {
+        scale: 1.0,
+        percent: 255.0,
+        min: 0.0,
+        max: 255.0,
+    };
+
+    fn function(src: &str) -> Function<'_> {
+        Function::parse(src).unwrap().unwrap()
+    }
+
+    fn error(src: &str) -> ParseError {
+        match Color::from_str(src, None) {
+            Err(Error::ParseError(err)) => err,
+            other => panic!("{src}: expected a parse error, got {other:?}"),
+        }
+    }
+
+    fn items(f: &Function) -> Vec<Value> {
+        f.values.iter().map(|value| value.item).collect()
+    }
+
+    #[test]
+    fn values_and_units() {
+        assert_eq!(Value::parse("1.5"), Some(Value::Number(1.5)));
+        assert_eq!(Value::parse("-.5e1"), Some(Value::Number(-5.0)));
+        assert_eq!(Value::parse("50%"), Some(Value::Percent(50.0)));
+        assert_eq!(Value::parse("90DEG"), Some(Value::Angle(90.0)));
+        assert_eq!(Value::parse("100grad"), Some(Value::Angle(90.0)));
+        assert_eq!(Value::parse("0.25turn"), Some(Value::Angle(90.0)));
+        assert_eq!(Value::parse("None"), Some(Value::None));
+        assert_eq!(Value::parse("1px"), None);
+        assert_eq!(Value::parse("."), None);
+    }
+
+    #[test]
+    fn legacy_and_modern_syntax() {
+        let legacy = function("RGB( 255 , 0,0 )");
+        let modern = function("rgb(255 0 0)");
+        assert_eq!(legacy.name(), "rgb");
+        assert_eq!(items(&legacy), items(&modern));
+        assert_eq!(function("rgb(255 0 0 / 50%)").len(), 4);
+        assert_eq!(Function::parse("red").unwrap(), None);
+        assert_eq!(
+            function("color(Display-P3 1 0 0)").space(),
+            Some("display-p3")
+        );
+        assert_eq!(function("color(display-p3 1 0 0)").len(), 3);
+        assert!(Function::parse("color(display-p3, 1, 0, 0)").is_err());
+    }
+
+    #[test]
+    fn syntax_errors() {
+        let err = error("rgb(255, 0 0)");
+        assert_eq!(err.offset, 11);
+        assert!(matches!(
+            err.kind,
+            ParseErrorKind::Expected {
+                expected: "`,`",
+                ..
+            }
+        ));
+
+        for (src, offset) in [
+            ("rgb(255, 0, 0 / 0.5)", 14),
+            ("rgb(255 0 0", 11),
+            ("rgb(255 0 0 /)", 13),
+            ("rgb(1 2 3 / 4 / 5)", 14),
+            ("rgb(,255,0,0)", 4),
+            ("rgb(255,0,0,)", 12),
+            ("rgb(255 red 0)", 8),
+            ("(1 2 3)", 0),
+        ] {
+            assert_eq!(error(src).offset, offset, "{src}");
+        }
+        assert!(Function::parse("rgb()").is_err());
+    }
+
+    #[test]
+    fn value_errors() {
+        let err = error("rgb(255 0)");
+        assert_eq!(err.offset, 9);
+        assert!(matches!(err.kind, ParseErrorKind::Count { found: 2, .. }));
+
+        let err = error("hsl(10% 0.5 0.5)");
+        assert_eq!(err.offset, 4);
+        assert!(matches!(
+            err.kind,
+            ParseErrorKind::Component { index: 1, .. }
+        ));
+
+        for (src, offset, index) in [
+            ("rgb(300, 0, 0)", 4, 1),
+            ("hsl(210 200 50)", 8, 2),
+            ("cmyk(0, 100, 100, 0)", 8, 2),
+            ("rgba(255, 0, 0, 256)", 16, 4),
+            ("rgb(100% 0 0 / 150%)", 15, 4),
+            ("xyy(0.3 0 1)", 8, 2),
+            ("xyy(0.3 -0.1 1)", 8, 2),
+        ] {
+            let err = error(src);
+            assert_eq!(err.offset, offset, "{src}");
+            assert!(
+                matches!(err.kind, ParseErrorKind::OutOfRange { index: i, .. } if i == index),
+                "{src}"
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_names() {
+        let err = error("rgbb(1 2 3)");
+        assert_eq!(err.offset, 0);
+        assert!(
+            matches!(err.kind, ParseErrorKind::Unknown { suggestion: Some(s), .. } if s == "rgb")
+        );
+
+        let err = error("color(display-p4 1 0 0)");
+        assert_eq!(err.offset, 6);
+        assert!(
+            matches!(err.kind, ParseErrorKind::Unknown { suggestion: Some(s), .. } if s == "display-p3")
+        );
+
+        assert_eq!(suggest("slateblu", ["red", "slateblue"]), Some("slateblue"));
+        assert_eq!(suggest("xyz", ["red", "slateblue"]), None);
+        assert_eq!(error("#12345").offset, 1);
+    }
+
+    #[test]
+    fn resolve_components() {
+        assert_eq!(CHANNEL.resolve(Value::Percent(50.0)), Some(127.5));
+        assert_eq!(CHANNEL.resolve(Value::Angle(90.0)), None);
+        assert_eq!(Component::Hue.resolve(Value::Percent(50.0)), None);
+        assert_eq!(Component::Hue.resolve(Value::None), Some(0.0));
+    }
+
+    #[test]
+    fn params_and_alpha() {
+        let f = function("f(0.5 50% none / 25%)");
+        assert!(f.params::<f32, 4>([UNIT; 4]).is_err());
+        assert!(f.params_with_alpha::<f32, 3>([UNIT; 3]).is_err());
+        assert_eq!(
+            f.params_with_alpha::<f32, 4>([UNIT; 4]).unwrap(),
+            [0.5, 0.5, 0.0, 0.25]
+        );
+        assert_eq!(
+            f.params_with_alpha::<u8, 4>([CHANNEL; 4]).unwrap(),
+            [1, 128, 0, 64]
+        );
+        assert!(
+            function("f(256, 0, 0)")
+                .params::<u8, 3>([CHANNEL; 3])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn other_notations() {
+        assert_eq!(error("0x12345").offset, 2);
+        assert_eq!(error("rgb-int(x)").offset, 8);
+        assert!(matches!(
+            error("rgb-int(0x1000000)").kind,
+            ParseErrorKind::OutOfRange { index: 1, .. }
+        ));
+        assert_eq!(error("kelvin(1000)").offset, 7);
+        assert_eq!(error("kelvin(1000").offset, 11);
+        assert_eq!(error("rgb:ff/00").offset, 9);
+        assert_eq!(error("rgb:ff/zz/00").offset, 7);
+    }
+}
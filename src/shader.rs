@@ -0,0 +1,134 @@
+//! Shader literals of linear light sRGB, what an engine expects when it applies
+//! the sRGB transfer function itself
+//!
+//! - `glsl-vec3` / `glsl-vec4`, `vec4(1.0, 0.0, 0.0, 1.0)`
+//! - `hlsl-float3` / `hlsl-float4`, `float4(1.0, 0.0, 0.0, 1.0)`
+//! - `wgsl-vec3` / `wgsl-vec4`, `vec4<f32>(1.0, 0.0, 0.0, 1.0)`
+//!
+//! Colors are gamut mapped into sRGB first, alpha is linear already
+
+use crate::color::Color;
+use crate::format::FormatParams;
+use crate::gamut::{GamutMapping, TargetGamut};
+use palette::LinSrgba;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShaderLanguage {
+    Glsl,
+    Hlsl,
+    Wgsl,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shader {
+    pub language: ShaderLanguage,
+    pub alpha: bool,
+}
+
+impl Shader {
+    pub fn from_name(name: &str) -> Option<Self> {
+        let (language, alpha) = match name {
+            "glsl-vec3" => (ShaderLanguage::Glsl, false),
+            "glsl-vec4" => (ShaderLanguage::Glsl, true),
+            "hlsl-float3" => (ShaderLanguage::Hlsl, false),
+            "hlsl-float4" => (ShaderLanguage::Hlsl, true),
+            "wgsl-vec3" => (ShaderLanguage::Wgsl, false),
+            "wgsl-vec4" => (ShaderLanguage::Wgsl, true),
+            _ => return None,
+        };
+
+        Some(Self { language, alpha })
+    }
+
+    pub fn format(self, color: Color, params: &FormatParams, mapping: GamutMapping) -> String {
+        let color = LinSrgba::from(<LinSrgba as TargetGamut>::map_gamut(color, mapping));
+        let (r, g, b, a) = color.into_components();
+
+        let components = match self.alpha {
+            true => vec![r, g, b, a],
+            false => vec![r, g, b],
+        };
+
+        let len = components.len();
+        let function = match self.language {
+            ShaderLanguage::Glsl => format!("vec{len}"),
+            ShaderLanguage::Hlsl => format!("float{len}"),
+            ShaderLanguage::Wgsl => format!("vec{len}<f32>"),
+        };
+
+        let sep = params.sep.as_deref().unwrap_or(", ");
+        let components = components
+            .into_iter()
+            .map(|c| float(params.number(c)))
+            .collect::<Vec<_>>()
+            .join(sep);
+
+        format!("{function}({components})")
+    }
+}
+
+/// Shading languages don't take `1` as a float, it has to be `1.0`
+fn float(number: String) -> String {
+    match number.contains(['.', 'e', 'E']) {
+        true => number,
+        false => format!("{number}.0"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(name: &str, color: Color, precision: Option<usize>) -> String {
+        let params = FormatParams {
+            precision,
+            ..Default::default()
+        };
+
+        Shader::from_name(name)
+            .unwrap()
+            .format(color, &params, GamutMapping::default())
+    }
+
+    #[test]
+    fn from_name() {
+        let shader = Shader::from_name("hlsl-float4").unwrap();
+        assert_eq!(shader.language, ShaderLanguage::Hlsl);
+        assert!(shader.alpha);
+        assert_eq!(Shader::from_name("glsl-vec2"), None);
+        assert_eq!(Shader::from_name("wgsl-float3"), None);
+    }
+
+    #[test]
+    fn literals() {
+        let red = Color::from_rgb([255, 0, 0]);
+        assert_eq!(
+            format("glsl-vec3", red.clone(), None),
+            "vec3(1.0, 0.0, 0.0)"
+        );
+        assert_eq!(
+            format("hlsl-float4", red.clone(), None),
+            "float4(1.0, 0.0, 0.0, 1.0)"
+        );
+        assert_eq!(
+            format("wgsl-vec4", red, Some(2)),
+            "vec4<f32>(1.00, 0.00, 0.00, 1.00)"
+        );
+    }
+
+    #[test]
+    fn linear_light() {
+        let gray = Color::from_frgba([0.5, 0.5, 0.5, 0.5]);
+        assert_eq!(
+            format("glsl-vec4", gray, Some(4)),
+            "vec4(0.2140, 0.2140, 0.2140, 0.5000)"
+        );
+    }
+
+    #[test]
+    fn float_literal() {
+        assert_eq!(float("1".to_owned()), "1.0");
+        assert_eq!(float("0.25".to_owned()), "0.25");
+        assert_eq!(float("1e-7".to_owned()), "1e-7");
+    }
+}
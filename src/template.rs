@@ -0,0 +1,275 @@
+//! User defined output formats from the `[formats]` table of the config
+//!
+//! A template is text with component placeholders, `"rgb({r} {g} {b} / {a%})"`,
+//! literal braces are written as `{{` and `}}`
+//!
+//! | placeholder       | float                | int       | percent   |
+//! |-------------------|----------------------|-----------|-----------|
+//! | `r`, `g`, `b`     | `0..=1`              | `0..=255` | `0..=100` |
+//! | `a`, `alpha`      | `0..=1`              | `0..=255` | `0..=100` |
+//! | `h`               | degrees              | degrees   | of `360`  |
+//! | `s`, `l`          | `0..=1`              | `0..=100` | `0..=100` |
+//! | `v`               | hsv value, `0..=1`   | `0..=100` | `0..=100` |
+//! | `okl`             | `0..=1`              | `0..=100` | `0..=100` |
+//! | `oka`, `okb`, `okc` | `-0.4..=0.4`       | of `0.4`  | of `0.4`  |
+//! | `okh`             | degrees              | degrees   | of `360`  |
+//!
+//! The kind is picked with `{r:int}`, `{r:float}` or `{r:percent}` / `{r%}`,
+//! without one `r`, `g` and `b` are ints and everything else is a float.
+//! RGB and HSL components are gamut mapped into sRGB, Oklab ones are not
+
+use crate::color::Color;
+use crate::format::FormatParams;
+use crate::gamut::{GamutMapping, TargetGamut};
+use crate::{Error, Result};
+use palette::{Hsla, Hsva, IntoColor, Oklaba, Oklcha, Srgba};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Channel {
+    Red,
+    Green,
+    Blue,
+    Alpha,
+    Hue,
+    Saturation,
+    Lightness,
+    Value,
+    OkL,
+    OkA,
+    OkB,
+    OkC,
+    OkH,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Int,
+    Float,
+    Percent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment<'a> {
+    Text(&'a str),
+    Placeholder(Channel, Option<Kind>),
+}
+
+/// A parsed user defined format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template<'a> {
+    segments: Vec<Segment<'a>>,
+}
+
+impl Channel {
+    fn from_name(name: &str) -> Option<Self> {
+        let channel = match name {
+            "r" => Self::Red,
+            "g" => Self::Green,
+            "b" => Self::Blue,
+            "a" | "alpha" => Self::Alpha,
+            "h" => Self::Hue,
+            "s" => Self::Saturation,
+            "l" => Self::Lightness,
+            "v" => Self::Value,
+            "okl" => Self::OkL,
+            "oka" => Self::OkA,
+            "okb" => Self::OkB,
+            "okc" => Self::OkC,
+            "okh" => Self::OkH,
+            _ => return None,
+        };
+
+        Some(channel)
+    }
+
+    /// What a float is multiplied by for int and percent
+    fn scale(self) -> (f32, f32) {
+        match self {
+            Self::Red | Self::Green | Self::Blue | Self::Alpha => (255.0, 100.0),
+            Self::Saturation | Self::Lightness | Self::Value | Self::OkL => (100.0, 100.0),
+            Self::OkA | Self::OkB | Self::OkC => (250.0, 250.0),
+            Self::Hue | Self::OkH => (1.0, 100.0 / 360.0),
+        }
+    }
+
+    fn default_kind(self) -> Kind {
+        match self {
+            Self::Red | Self::Green | Self::Blue => Kind::Int,
+            _ => Kind::Float,
+        }
+    }
+}
+
+impl<'a> Template<'a> {
+    pub fn parse(src: &'a str) -> Result<Self> {
+        let err = || Error::FailedToParseFormat(src.to_owned());
+
+        let mut segments = Vec::new();
+        let mut rest = src;
+
+        while let Some(i) = rest.find(['{', '}']) {
+            if i > 0 {
+                segments.push(Segment::Text(&rest[..i]));
+            }
+
+            let escaped = &rest[i..i + 1];
+
+            if rest[i + 1..].starts_with(escaped) {
+                segments.push(Segment::Text(escaped));
+                rest = &rest[i + 2..];
+                continue;
+            }
+
+            if escaped == "}" {
+                return Err(err());
+            }
+
+            let end = rest[i..].find('}').ok_or_else(err)? + i;
+            segments.push(parse_placeholder(&rest[i + 1..end]).ok_or_else(err)?);
+            rest = &rest[end + 1..];
+        }
+
+        if !rest.is_empty() {
+            segments.push(Segment::Text(rest));
+        }
+
+        Ok(Self { segments })
+    }
+
+    pub fn render(&self, color: Color, params: &FormatParams, mapping: GamutMapping) -> String {
+        let oklch = Oklcha::from(color.clone());
+        let oklab = Oklaba::from(color.clone());
+        let rgb = Srgba::from(<Srgba as TargetGamut>::map_gamut(color, mapping));
+        let hsl: Hsla = rgb.into_color();
+        let hsv: Hsva = rgb.into_color();
+
+        let mut dst = String::new();
+
+        for segment in &self.segments {
+            let (channel, kind) = match segment {
+                Segment::Text(text) => {
+                    dst.push_str(text);
+                    continue;
+                }
+                Segment::Placeholder(channel, kind) => {
+                    (*channel, kind.unwrap_or(channel.default_kind()))
+                }
+            };
+
+            let value = match channel {
+                Channel::Red => rgb.red,
+                Channel::Green => rgb.green,
+                Channel::Blue => rgb.blue,
+                Channel::Alpha => rgb.alpha,
+                Channel::Hue => hsl.hue.into_positive_degrees(),
+                Channel::Saturation => hsl.saturation,
+                Channel::Lightness => hsl.lightness,
+                Channel::Value => hsv.value,
+                Channel::OkL => oklch.l,
+                Channel::OkA => oklab.a,
+                Channel::OkB => oklab.b,
+                Channel::OkC => oklch.chroma,
+                Channel::OkH => oklch.hue.into_positive_degrees(),
+            };
+
+            let (int, percent) = channel.scale();
+
+            match kind {
+                Kind::Int => dst.push_str(&((value * int).round() as i64).to_string()),
+                Kind::Float => dst.push_str(&params.number(value)),
+                Kind::Percent => {
+                    dst.push_str(&params.number(value * percent));
+                    dst.push('%');
+                }
+            }
+        }
+
+        dst
+    }
+}
+
+/// `r`, `r%` or `r:int`
+fn parse_placeholder(src: &str) -> Option<Segment<'_>> {
+    let src = src.trim();
+
+    let (name, kind) = match src.split_once(':') {
+        Some((name, kind)) => {
+            let kind = match kind.trim() {
+                "int" => Kind::Int,
+                "float" => Kind::Float,
+                "percent" | "%" => Kind::Percent,
+                _ => return None,
+            };
+
+            (name.trim(), Some(kind))
+        }
+        None => match src.strip_suffix('%') {
+            Some(name) => (name, Some(Kind::Percent)),
+            None => (src, None),
+        },
+    };
+
+    Some(Segment::Placeholder(Channel::from_name(name)?, kind))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(template: &str, color: Color) -> String {
+        let params = FormatParams {
+            precision: Some(1),
+            ..Default::default()
+        };
+
+        Template::parse(template)
+            .unwrap()
+            .render(color, &params, GamutMapping::default())
+    }
+
+    #[test]
+    fn parse_segments() {
+        let template = Template::parse("{{{ r:int }}} {a%}").unwrap();
+        let segments = [
+            Segment::Text("{"),
+            Segment::Placeholder(Channel::Red, Some(Kind::Int)),
+            Segment::Text("}"),
+            Segment::Text(" "),
+            Segment::Placeholder(Channel::Alpha, Some(Kind::Percent)),
+        ];
+        assert_eq!(template.segments, segments);
+    }
+
+    #[test]
+    fn parse_errors() {
+        for src in ["{x}", "{r", "r}", "{r:bad}", "{}"] {
+            assert!(Template::parse(src).is_err(), "{src}");
+        }
+    }
+
+    #[test]
+    fn render_kinds() {
+        let color = Color::from_rgba([255, 128, 0, 128]);
+        assert_eq!(
+            render("rgb({r} {g} {b} / {a%})", color.clone()),
+            "rgb(255 128 0 / 50.2%)"
+        );
+        assert_eq!(
+            render("{r:float} {g:percent} {alpha:int}", color),
+            "1.0 50.2% 128"
+        );
+    }
+
+    #[test]
+    fn render_hsl_and_oklab() {
+        let green = Color::from_rgb([0, 255, 0]);
+        assert_eq!(
+            render("{h:int} {h} {h%} {s:int} {l%}", green.clone()),
+            "120 120.0 33.3% 100 50.0%"
+        );
+        assert_eq!(
+            render("{okl%} {okh:int}", Color::from_rgb([255, 0, 0])),
+            "62.8% 29"
+        );
+    }
+}